@@ -1,4 +0,0 @@
-fn main() {
-  // TODO: generate 100 random networks, load them in `tests.rs`, and property test their outputs
-  //       against the dynamic (assumed correct) version
-}
\ No newline at end of file
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use cge::{Network, gene::NeuronId};
+use proc_macro2::{TokenStream, Ident};
+use quote::{quote, format_ident};
+use syn::Visibility;
+use crate::{numeric_type::NumericType, evaluator::{self, Precision}, layered::{self, Source, Terms}, head::Head};
+
+/// How many instances a bank evaluates at once (so stack use does not grow with the size of the bank).
+const LANES: usize = 8;
+
+/// The body of `<Name>Bank<N>::evaluate_chunk`: the network's arithmetic, once, over `[T; LANES]` lanes (one lane per instance).
+/// - `inputs[j]`, `outputs[k]` and `persistence[s]` are lanes, every neuron becomes a lane too.
+/// - Each neuron is summed exactly like `evaluator::evaluate` (same order, same precision), so every lane is bitwise identical to `evaluate`.
+/// - `write` stores output `index` of one `lane`, given its value (usually `outputs[index][lane] = value;`).
+pub fn lane_evaluation(
+  network:          &Network<f64>,
+  recurrence_table: &HashMap<NeuronId, usize>,
+  numeric_type:     NumericType,
+  precision:        Precision,
+  activation:       TokenStream,
+  write:            impl Fn(usize, TokenStream) -> TokenStream,
+) -> TokenStream {
+  let Terms { neurons, order, outputs } = layered::terms(network, recurrence_table);
+  let numeric_token = numeric_type.token();
+  let weight_of = |weight: f64| precision.accumulate.naive_conversion(weight);
+
+  let name = |id: NeuronId| format_ident!("n{}", id.as_usize());
+  let term = |term: &layered::Term| match term.source {
+    Source::Input(index)     => evaluator::Term::Product { weight: weight_of(term.weight), value: quote!(inputs[#index][lane]) },
+    Source::Bias(value)      => evaluator::Term::Constant(weight_of(value)),
+    Source::Recurrent(state) => evaluator::Term::Product { weight: weight_of(term.weight), value: quote!(persistence[#state][lane]) },
+    Source::Neuron(id)       => { let name = name(id); evaluator::Term::Product { weight: weight_of(term.weight), value: quote!(#name[lane]) } }
+  };
+  let narrow = |sum: TokenStream| if precision.accumulate == numeric_type { sum } else { quote!((#sum) as #numeric_token) };
+
+  // one neuron at a time (every neuron after the ones it reads), for every instance
+  let computations = Terms::layers(&neurons, &order).concat().into_iter().map(|id| {
+    let lane = name(id);
+    let sum = narrow(evaluator::sum(&neurons[&id].iter().map(term).collect::<Vec<_>>(), numeric_type, precision));
+    quote! {
+      let mut #lane = [0.0; LANES];
+      for lane in 0..LANES { #lane[lane] = #activation(#sum); }
+    }
+  });
+
+  let writes = outputs.iter().enumerate().map(|(index, output)| {
+    let write = write(index, narrow(evaluator::sum(&[term(output)], numeric_type, precision)));
+    quote! { for lane in 0..LANES { #write } }
+  });
+
+  // recurrent state is updated at the very end
+  let updates = recurrence_table.iter().map(|(&id, &state)| {
+    let lane = name(id);
+    quote! { persistence[#state] = #lane; }
+  });
+
+  quote! {
+    #(#computations)*
+    #(#writes)*
+    #(#updates)*
+  }
+}
+
+/// Generate `<Name>Bank<N>`: `N` instances of a recurrent network, each with its own recurrent state,
+/// evaluated `LANES` at a time by the same code (one lane per instance, see `lane_evaluation`).
+/// - Also generates `<Name>State`, the type of the network's snapshots.
+pub fn bank(
+  name:             &Ident,
+  visibility:       &Visibility,
+  numeric_type:     NumericType,
+  recurrency_count: usize,
+  fingerprint:      u64,
+  lanes:            &TokenStream,
+  head:             Option<&Head>
+) -> TokenStream {
+  let bank = format_ident!("{}Bank", name);
+  let state = format_ident!("{}State", name);
+  let state_documentation = format!("A snapshot of the recurrent state of [`{}`] (see `{}::snapshot`).", name, name);
+  let numeric_token = numeric_type.token();
+  let documentation = format!(
+"A bank of `N` independent instances of [`{name}`].
+- Every instance has its own recurrent state (`[[{numeric_token}; {name}::PERSISTENT_SIZE]; N]`, one row per instance), code and weights are shared.
+- Instances are evaluated {LANES} at a time: each neuron is computed for {LANES} instances at once (which vectorizes).
+- Stack use does not grow with `N` (about `{name}::NEURON_COUNT * {LANES}` values), so banks can hold thousands of agents.
+- Useful for evaluating many agents through the same controller.",
+  );
+
+  // the head works on one instance's outputs at a time
+  let head = head.map(|head| {
+    let apply = head.apply(numeric_type);
+    quote! {
+      for lane in 0..LANES {
+        let mut instance: [#numeric_token; #name::OUTPUT_COUNT] = core::array::from_fn(|k| outputs[k][lane]);
+        {
+          let outputs = &mut instance;
+          #apply
+        }
+        for (k, value) in instance.into_iter().enumerate() { outputs[k][lane] = value; }
+      }
+    }
+  });
+
+  quote! {
+    #[doc = #state_documentation]
+    #visibility type #state = const_cge::State<#numeric_token, #recurrency_count, #fingerprint>;
+
+    #[doc = #documentation]
+    #[derive(Clone, Copy)]
+    #visibility struct #bank<const N: usize> {
+      persistence: [[#numeric_token; #recurrency_count]; N],
+    }
+
+    impl<const N: usize> Default for #bank<N> {
+      /// Every instance starts with all-zero recurrent state.
+      fn default() -> Self {
+        Self { persistence: [[0.0; #recurrency_count]; N] }
+      }
+    }
+
+    impl<const N: usize> #bank<N> {
+      /// The number of instances in the bank. Provided for convenience (const).
+      pub const INSTANCE_COUNT: usize = N;
+
+      /// The number of instances evaluated at once (each neuron is computed for this many instances). Provided for convenience (const).
+      pub const LANES: usize = #LANES;
+
+      /// Create a bank with the given recurrent state (`persistence[i]` is the state of instance `i`).
+      pub fn with_recurrent_state(persistence: &[[#numeric_token; #recurrency_count]; N]) -> Self {
+        Self { persistence: *persistence }
+      }
+
+      /// Get a reference to the recurrent state of every instance (`[i]` is the state of instance `i`).
+      pub fn recurrent_state(&self) -> &[[#numeric_token; #recurrency_count]; N] {
+        &self.persistence
+      }
+
+      /// Get a mutable reference to the recurrent state of every instance (`[i]` is the state of instance `i`).
+      pub fn recurrent_state_mut(&mut self) -> &mut [[#numeric_token; #recurrency_count]; N] {
+        &mut self.persistence
+      }
+
+      /// The recurrent state of one instance (as its own network's `recurrent_state` would be).
+      pub fn instance_state(&self, instance: usize) -> [#numeric_token; #recurrency_count] {
+        self.persistence[instance]
+      }
+
+      /// Overwrite the recurrent state of one instance.
+      pub fn set_instance_state(&mut self, instance: usize, state: &[#numeric_token; #recurrency_count]) {
+        self.persistence[instance] = *state;
+      }
+
+      /// Evaluate every instance once, instance `i` reading `inputs[i]` and writing `outputs[i]`.
+      /// - Identical to calling `evaluate` on `N` separate networks.
+      pub fn evaluate_all(
+        &mut self,
+        inputs:  &[[#numeric_token; #name::INPUT_COUNT]; N],
+        outputs: &mut [[#numeric_token; #name::OUTPUT_COUNT]; N]
+      ) {
+        self.evaluate_chunks(|i, j| inputs[i][j], |i, k, value| outputs[i][k] = value);
+      }
+
+      /// Evaluate every instance once, with inputs and outputs as lanes: `inputs[j][i]` is input `j` of instance `i`, `outputs[k][i]` is its output `k`.
+      /// - Identical to calling `evaluate` on `N` separate networks.
+      pub fn evaluate_lanes(
+        &mut self,
+        inputs:  &[[#numeric_token; N]; #name::INPUT_COUNT],
+        outputs: &mut [[#numeric_token; N]; #name::OUTPUT_COUNT]
+      ) {
+        self.evaluate_chunks(|i, j| inputs[j][i], |i, k, value| outputs[k][i] = value);
+      }
+
+      /// Evaluate every instance, `Self::LANES` at a time: `input(i, j)` is input `j` of instance `i`, `output(i, k, value)` stores its output `k`.
+      /// - A last, partial chunk is padded with zeros (and the padding is thrown away).
+      #[inline]
+      fn evaluate_chunks(&mut self, input: impl Fn(usize, usize) -> #numeric_token, mut output: impl FnMut(usize, usize, #numeric_token)) {
+        for base in (0..N).step_by(#LANES) {
+          let width = (N - base).min(#LANES);
+          let inputs = core::array::from_fn(|j| core::array::from_fn(|lane| if lane < width { input(base + lane, j) } else { 0.0 }));
+          let mut persistence = core::array::from_fn(|s| core::array::from_fn(|lane| if lane < width { self.persistence[base + lane][s] } else { 0.0 }));
+          let mut outputs = [[0.0; #LANES]; #name::OUTPUT_COUNT];
+          Self::evaluate_chunk(&mut persistence, &inputs, &mut outputs);
+
+          for lane in 0..width {
+            for (s, state) in self.persistence[base + lane].iter_mut().enumerate() { *state = persistence[s][lane]; }
+            for (k, outputs) in outputs.iter().enumerate() { output(base + lane, k, outputs[lane]); }
+          }
+        }
+      }
+
+      /// Evaluate `LANES` instances once, on lanes (`[j][lane]`, `[k][lane]`, `[s][lane]`).
+      /// - Each neuron is computed for every instance before the next neuron (elementwise over `[T; LANES]`).
+      #[inline]
+      fn evaluate_chunk(
+        persistence: &mut [[#numeric_token; #LANES]; #recurrency_count],
+        inputs:      &[[#numeric_token; #LANES]; #name::INPUT_COUNT],
+        outputs:     &mut [[#numeric_token; #LANES]; #name::OUTPUT_COUNT]
+      ) {
+        const LANES: usize = #LANES;
+        #lanes
+        #head
+      }
+    }
+  }
+}
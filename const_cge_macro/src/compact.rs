@@ -0,0 +1,197 @@
+use std::{collections::{HashMap, HashSet}, ops::Range};
+use cge::{Network, gene::{Gene, NeuronId}};
+use proc_macro2::TokenStream;
+use quote::quote;
+use crate::numeric_type::NumericType;
+
+/// A bytecode implementation of `evaluate`.
+pub struct Compact {
+  /// The `evaluate` function (running a `static` program on `const_cge::compact`'s interpreter)
+  pub evaluate_function: TokenStream,
+
+  /// Markdown describing the program
+  pub documentation:     String,
+}
+
+/// Walks the genome in the same order as `evaluator::evaluate`, emitting instructions instead of arithmetic.
+struct Compiler<'a> {
+  network:          &'a Network<f64>,
+  recurrence_table: &'a HashMap<NeuronId, usize>,
+  numeric_type:     NumericType,
+
+  /// Neurons whose (unweighted) output is read again later (sources of forward or recurrent jumpers)
+  registers:        HashMap<NeuronId, u16>,
+
+  /// Neurons computed so far during this evaluation
+  evaluated:        HashSet<NeuronId>,
+
+  instructions:     Vec<TokenStream>,
+  stack_size:       usize,
+  max_stack_size:   usize,
+}
+
+/// Indices are packed into `u16`s to keep the bytecode small.
+fn pack(index: usize, what: &str) -> u16 {
+  u16::try_from(index).unwrap_or_else(|_| panic!("Network is too large for `mode = compact` ({} {} does not fit in a `u16`).", what, index))
+}
+
+impl<'a> Compiler<'a> {
+  fn push(&mut self, instruction: TokenStream) {
+    self.instructions.push(quote!(Instruction::#instruction));
+    self.stack_size += 1;
+    self.max_stack_size = self.max_stack_size.max(self.stack_size);
+  }
+
+  fn compile(&mut self, range: Range<usize>, ignore_final_neuron_weight: bool) {
+    let genome = self.network.genome();
+    let numeric_type = self.numeric_type;
+
+    for gene_index in range.clone().rev() {
+      match &genome[gene_index] {
+        Gene::Input(input) => {
+          let index = pack(input.id().as_usize(), "input ID");
+          let weight = numeric_type.naive_conversion(input.weight());
+          self.push(quote!(Input { index: #index, weight: #weight }));
+        },
+        Gene::Neuron(n) => {
+          let input_count = n.num_inputs();
+          if input_count == 0 || input_count > self.stack_size {
+            panic!("Corrupt CGE: neuron (ID {:?}) did not receive enough inputs (expected {}, but only received {})", n.id(), input_count, self.stack_size);
+          }
+
+          let inputs = pack(input_count, "neuron input count");
+          self.instructions.push(quote!(Instruction::Neuron { inputs: #inputs }));
+          self.stack_size -= input_count - 1;
+          self.evaluated.insert(n.id());
+
+          if let Some(register) = self.registers.get(&n.id()) {
+            self.instructions.push(quote!(Instruction::Store { register: #register }));
+          }
+
+          // a forward jumper reads the neuron's output through its own weight, instead of the neuron's weight
+          if !ignore_final_neuron_weight || gene_index != range.start {
+            let weight = numeric_type.naive_conversion(n.weight());
+            self.instructions.push(quote!(Instruction::Weight { weight: #weight }));
+          }
+        },
+        Gene::ForwardJumper(f) => {
+          let id = f.source_id();
+          let weight = numeric_type.naive_conversion(f.weight());
+
+          if self.evaluated.contains(&id) {
+            let register = self.registers[&id];
+            self.push(quote!(Load { register: #register, weight: #weight }));
+          } else {
+            // evaluate the source subnetwork right here, it leaves its (unweighted, stored) output on the stack
+            let subnetwork_range = self.network
+              .neuron_info_map()
+              .get(&id)
+              .unwrap_or_else(|| panic!("Corrupt CGE: encountered a forward jumper (gene {}) with an invalid neuron ID ({})", gene_index, id.as_usize()))
+              .subgenome_range();
+
+            self.compile(subnetwork_range, true);
+            self.instructions.push(quote!(Instruction::Weight { weight: #weight }));
+          }
+        },
+        Gene::RecurrentJumper(r) => {
+          let state = self.recurrence_table
+            .get(&r.source_id())
+            .unwrap_or_else(|| panic!("Corrupt CGE: encountered a recurrent connection (gene {}) with an invalid neuron ID ({})", gene_index, r.source_id().as_usize()));
+
+          let state = pack(*state, "recurrent state index");
+          let weight = numeric_type.naive_conversion(r.weight());
+          self.push(quote!(Recurrent { state: #state, weight: #weight }));
+        },
+        Gene::Bias(b) => {
+          let value = numeric_type.naive_conversion(b.value());
+          self.push(quote!(Bias { value: #value }));
+        }
+      }
+    }
+  }
+}
+
+/// Compile the network into bytecode for `const_cge::compact::Program`,
+/// and generate an `evaluate` which runs it.
+pub fn synthesize_compact(
+  network:          &Network<f64>,
+  recurrence_table: &HashMap<NeuronId, usize>,
+  numeric_type:     NumericType,
+  activation:       TokenStream,
+) -> Compact {
+  // every neuron read by a jumper gets a register
+  let mut registers = HashMap::new();
+  for gene in network.genome() {
+    let source = match gene {
+      Gene::ForwardJumper(f)   => f.source_id(),
+      Gene::RecurrentJumper(r) => r.source_id(),
+      _ => continue
+    };
+
+    let register = pack(registers.len(), "register");
+    registers.entry(source).or_insert(register);
+  }
+
+  let mut compiler = Compiler {
+    network,
+    recurrence_table,
+    numeric_type,
+    registers,
+    evaluated:      HashSet::new(),
+    instructions:   vec![],
+    stack_size:     0,
+    max_stack_size: 0,
+  };
+  compiler.compile(0..network.len(), false);
+
+  // persistence is updated from the registers of the recurrent sources, once evaluation is over
+  let mut persist = vec![0; recurrence_table.len()];
+  for (id, &state) in recurrence_table {
+    persist[state] = compiler.registers[id];
+  }
+
+  let Compiler { instructions, stack_size: output_count, max_stack_size: stack_size, registers, .. } = compiler;
+  let register_count = registers.len();
+  let input_count = network.num_inputs();
+  let recurrency_count = recurrence_table.len();
+  let numeric_token = numeric_type.token();
+
+  let documentation = format!(
+"Compiled to bytecode
+  - {instruction_count} instructions, run by the interpreter in `const_cge::compact`
+  - `Self::evaluate` uses {scratch_count} `{numeric}`s of scratch space on the stack ({stack_size} stack, {register_count} registers)",
+    instruction_count = instructions.len(),
+    scratch_count = stack_size + register_count,
+    numeric = numeric_token,
+  );
+
+  let (self_argument, persistence) = if recurrency_count == 0 {
+    (quote!(), quote!(&mut []))
+  } else {
+    (quote!(&mut self,), quote!(&mut self.persistence))
+  };
+
+  let evaluate_function = quote! {
+    /// Evaluate the network for a single input vector, by interpreting its bytecode.
+    ///
+    /// Properties:
+    /// - allocationless, heapless, no_std compatible
+    /// - the network is stored as data, the interpreter is shared by every network with the same numeric type
+    /// - bitwise identical to `mode = arithmetic`, but slower
+    pub fn evaluate(#self_argument inputs: &[#numeric_token; #input_count], outputs: &mut [#numeric_token; #output_count]) {
+      use const_cge::compact::{Program, Instruction};
+
+      static PROGRAM: Program<#numeric_token> = Program {
+        instructions: &[#(#instructions),*],
+        persist:      &[#(#persist),*],
+        activation:   #activation,
+      };
+
+      let mut stack:     [#numeric_token; #stack_size]     = [0.0; #stack_size];
+      let mut registers: [#numeric_token; #register_count] = [0.0; #register_count];
+      PROGRAM.evaluate(inputs, outputs, #persistence, &mut stack, &mut registers);
+    }
+  };
+
+  Compact { evaluate_function, documentation }
+}
@@ -0,0 +1,394 @@
+use std::collections::HashMap;
+use proc_macro2::TokenStream;
+use quote::{quote, format_ident};
+use syn::{Token, Item, Fields, Expr, ExprLit, Lit, LitInt, parse_quote};
+use crate::{evaluator::{self, ResultNames, OperationCounts, Precision}, numeric_type::NumericType, recurrency, synthesis::{load_network, activation_path, persistence}, invocation_parser::{manifest_relative, parse_numeric_type}, macro_core::rebuild_anchor, mode::float_token, pipeline::stage_impl, fingerprint::{fingerprint, ensemble_fingerprint}, verification::{verification_module, Reference}, bank::{bank, lane_evaluation}};
+
+/// How the outputs of the members are combined
+#[derive(Clone, Copy)]
+pub enum Combine {
+  /// Average of every member's outputs.
+  Mean,
+
+  /// Median of every member's outputs (the mean of the middle two, for an even number of members).
+  Median,
+
+  /// Each member votes for its largest output, outputs become the share of votes (for classifiers).
+  Vote
+}
+
+impl Combine {
+  pub const VARIANTS_LIST: &'static str = "mean, median, vote";
+
+  pub fn name(self) -> &'static str {
+    match self { Combine::Mean => "mean", Combine::Median => "median", Combine::Vote => "vote" }
+  }
+}
+
+/// `#[ensemble("a.cge", "b.cge", ..., combine = mean)]`
+pub struct EnsembleInvocation {
+  /// The CGE files of the members (relative to the crate root)
+  pub members:      Vec<String>,
+  pub combine:      Combine,
+  pub numeric_type: NumericType,
+
+  /// Generate a test against the members in the `cge` runtime, with this many random trials
+  pub verify:       Option<usize>,
+}
+
+impl EnsembleInvocation {
+  pub const ARGUMENTS_LIST: &'static str = "combine, numeric_type, verify";
+}
+
+impl syn::parse::Parse for EnsembleInvocation {
+  fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
+    // the members come first
+    let mut members = vec![];
+    while let Ok(Expr::Lit(ExprLit { lit: Lit::Str(path), .. })) = input.fork().parse::<Expr>() {
+      let _ = input.parse::<Expr>()?;
+      members.push(path.value());
+      if input.parse::<Option<Token![,]>>()?.is_none() { break; }
+    }
+
+    if members.len() < 2 { panic!("An ensemble needs at least two CGE files, like `#[ensemble(\"a.cge\", \"b.cge\", combine = mean)]`."); }
+
+    let mut invocation = EnsembleInvocation { members, combine: Combine::Mean, numeric_type: NumericType::Float64, verify: None };
+
+    // remaining arguments (`name = value`, in any order)
+    while !input.is_empty() {
+      let arg_name = input.parse::<syn::Ident>()?;
+      let _: Token![=] = input.parse()
+        .unwrap_or_else(|_| panic!("Expected '=' after `{}` argument.", arg_name));
+
+      match arg_name.to_string().as_ref() {
+        "combine" => {
+          let combine = input.parse::<syn::Ident>()
+            .unwrap_or_else(|_| panic!("Expected one of {{ {} }} after `combine = `.", Combine::VARIANTS_LIST));
+
+          invocation.combine = match combine.to_string().as_ref() {
+            "mean"   => Combine::Mean,
+            "median" => Combine::Median,
+            "vote"   => Combine::Vote,
+            _ => return Err(syn::Error::new_spanned(&combine, format!("Invalid `combine`. Please use one of {{ {} }}.", Combine::VARIANTS_LIST)))
+          };
+        },
+        "numeric_type" => invocation.numeric_type = parse_numeric_type(input, "numeric_type"),
+        "verify" => {
+          let trials = input.parse::<LitInt>()
+            .unwrap_or_else(|_| panic!("Expected an integer after `verify = ` (the number of random trials to test)."));
+
+          invocation.verify = Some(trials.base10_parse()?);
+        },
+        _ => return Err(syn::Error::new_spanned(&arg_name, format!("Nonsense `ensemble` argument `{}`. Expected one of {{ {} }}.", arg_name, EnsembleInvocation::ARGUMENTS_LIST)))
+      }
+
+      // tolerate a trailing comma
+      if input.parse::<Option<Token![,]>>()?.is_none() { break; }
+    }
+
+    Ok(invocation)
+  }
+}
+
+/// One member, compiled: its arithmetic, the values of its outputs, and its recurrent state updates.
+struct Member {
+  computations: Vec<TokenStream>,
+  outputs:      Vec<TokenStream>,
+  updates:      Vec<TokenStream>,
+}
+
+impl Member {
+  /// The member's block: `write` stores each output (given its index and value), before the recurrent state is updated.
+  fn block(&self, write: impl Fn(usize, &TokenStream) -> TokenStream) -> TokenStream {
+    let Member { computations, outputs, updates, .. } = self;
+    let writes = outputs.iter().enumerate().map(|(index, value)| write(index, value));
+    quote!(#(#computations)* #(#writes)* #(#updates)*)
+  }
+}
+
+/// Compile every member into one `evaluate`: members share the inputs, their recurrent state is laid out
+/// back to back in one persistence array, and their outputs are combined.
+/// - Means and votes are accumulated into `outputs` as each member runs, only medians need every member's outputs at once.
+pub fn ensemble(invocation: EnsembleInvocation, item: Item) -> TokenStream {
+  let EnsembleInvocation { members: paths, combine, numeric_type, verify } = invocation;
+  let numeric_token = numeric_type.token();
+  let precision = Precision { fma: false, accumulate: numeric_type };
+
+  let mut members = vec![];
+  let mut sources = vec![];
+  let mut fingerprints = vec![];
+  let mut recurrency_count = 0;
+  let mut dimensions = None;
+
+  // every member, and where its recurrent state lives in the ensemble's persistence array (for the bank)
+  let mut networks = vec![];
+
+  for path in &paths {
+    let (network, source) = load_network(path);
+
+    // every member must fit the same interface
+    let (input_count, output_count) = (network.num_inputs(), network.num_outputs());
+    match dimensions {
+      None => dimensions = Some((input_count, output_count, path)),
+      Some((inputs, outputs, first)) => if (inputs, outputs) != (input_count, output_count) {
+        panic!("Ensemble members must share their dimensions: `{}` has {} inputs and {} outputs, but `{}` has {} inputs and {} outputs.", first, inputs, outputs, path, input_count, output_count);
+      }
+    }
+
+    // this member's recurrent state starts where the previous member's ends
+    let recurrency_table: HashMap<_, _> = recurrency::identify_recurrence(&network)
+      .into_iter()
+      .map(|(id, index)| (id, recurrency_count + index))
+      .collect();
+    recurrency_count += recurrency_table.len();
+
+    let (mut computations, mut updates) = (vec![], vec![]);
+    let outputs = evaluator::evaluate(
+      network.genome(),
+      network.neuron_info_map(),
+      0..network.len(),
+      true,
+      false,
+      true,
+      &mut computations,
+      &mut updates,
+      &mut ResultNames::default(),
+      &mut HashMap::new(),
+      &mut OperationCounts::default(),
+      &recurrency_table,
+      numeric_type,
+      precision,
+      activation_path(network.activation(), numeric_type)
+    ).expect("Corrupt CGE: network appears to have no outputs");
+
+    fingerprints.push(fingerprint(&network, numeric_type));
+    sources.push(source);
+    members.push(Member { computations, outputs, updates });
+    networks.push((network, recurrency_table));
+  }
+
+  let (input_count, output_count, _) = dimensions.unwrap();
+  let member_count = members.len();
+  let member_indices: Vec<usize> = (0..member_count).collect();
+  let count = float_token(member_count as f64);
+
+  let (name, visibility) = match item {
+    Item::Struct(ref s) => {
+      if recurrency_count != 0 && !s.fields.is_empty() { panic!("Your ensemble is recurrent. Only unit structs (no fields) support recurrency at this time."); }
+      (s.ident.clone(), s.vis.clone())
+    },
+    _ => panic!("Unsupported language construct (`struct` only).")
+  };
+
+  let fingerprint = ensemble_fingerprint(&fingerprints, combine.name());
+  let (fingerprint_bytes, topology_bytes, fingerprint_hex) = (fingerprint.full, fingerprint.topology, fingerprint.hex());
+  let (persistence_field, persistence_methods) = persistence(numeric_type, recurrency_count, fingerprint.tag());
+
+  let item = match item {
+    Item::Struct(mut s) if recurrency_count != 0 => {
+      s.fields = Fields::Named(parse_quote!({ #persistence_field }));
+      Item::Struct(s)
+    },
+    item => item
+  };
+
+  // should `evaluate` get a `&mut self`, or can it be a static function?
+  let self_argument = if recurrency_count == 0 { quote!() } else { quote!(&mut self,) };
+  let evaluate_members = if recurrency_count == 0 { quote!(Self::evaluate_members) } else { quote!(self.evaluate_members) };
+
+  // (the middle of sorted values, for medians)
+  let median = {
+    let middle = member_count / 2;
+    if member_count % 2 == 1 { quote!(sorted[#middle]) } else {
+      let below = middle - 1;
+      quote!((sorted[#below] + sorted[#middle]) * 0.5)
+    }
+  };
+
+  let members_function = {
+    let blocks = members.iter().enumerate().map(|(member, compiled)| compiled.block(|index, value| quote!(members[#member][#index] = #value;)));
+    quote!(#({ #blocks })*)
+  };
+
+  let evaluate_function = match combine {
+    Combine::Mean => {
+      let blocks = members.iter().enumerate().map(|(member, compiled)| compiled.block(|index, value| {
+        if member == 0 { quote!(outputs[#index] = #value;) } else { quote!(outputs[#index] += #value;) }
+      }));
+      quote! {
+        #({ #blocks })*
+        for output in outputs.iter_mut() { *output /= #count; }
+      }
+    },
+    Combine::Median => quote! {
+      let mut members = [[0.0; #output_count]; #member_count];
+      #evaluate_members(inputs, &mut members);
+      for (index, output) in outputs.iter_mut().enumerate() {
+        let sorted = const_cge::ensemble::sorted([#(members[#member_indices][index]),*]);
+        *output = #median;
+      }
+    },
+    Combine::Vote => {
+      let blocks = members.iter().map(|compiled| {
+        let values = &compiled.outputs;
+        let Member { computations, updates, .. } = compiled;
+        quote! {
+          #(#computations)*
+          let member = [#(#values),*];
+          #(#updates)*
+          outputs[const_cge::head::argmax(&member)] += 1.0 / #count;
+        }
+      });
+      quote! {
+        *outputs = [0.0; #output_count];
+        #({ #blocks })*
+      }
+    }
+  };
+
+  // recurrent ensembles get a bank, each member on lanes, combined lane by lane (exactly like `evaluate`)
+  let bank = if recurrency_count == 0 { None } else {
+    let activation = |network: &cge::Network<f64>| activation_path(network.activation(), numeric_type);
+    let lanes: Vec<TokenStream> = networks.iter().enumerate().map(|(member, (network, recurrency_table))| {
+      let lanes = match combine {
+        Combine::Mean => lane_evaluation(network, recurrency_table, numeric_type, precision, activation(network), |index, value| {
+          if member == 0 { quote!(outputs[#index][lane] = #value;) } else { quote!(outputs[#index][lane] += #value;) }
+        }),
+        Combine::Median => lane_evaluation(network, recurrency_table, numeric_type, precision, activation(network), |index, value| quote!(members[#member][#index][lane] = #value;)),
+        Combine::Vote => {
+          let lanes = lane_evaluation(network, recurrency_table, numeric_type, precision, activation(network), |index, value| quote!(member[#index][lane] = #value;));
+          quote! {
+            #lanes
+            for lane in 0..LANES {
+              let votes: [#numeric_token; #output_count] = core::array::from_fn(|index| member[index][lane]);
+              outputs[const_cge::head::argmax(&votes)][lane] += 1.0 / #count;
+            }
+          }
+        }
+      };
+      quote!({ #lanes })
+    }).collect();
+
+    let lanes = match combine {
+      Combine::Mean => quote! {
+        #(#lanes)*
+        for output in outputs.iter_mut() {
+          for value in output.iter_mut() { *value /= #count; }
+        }
+      },
+      Combine::Median => quote! {
+        let mut members = [[[0.0; LANES]; #output_count]; #member_count];
+        #(#lanes)*
+        for (index, output) in outputs.iter_mut().enumerate() {
+          for (lane, output) in output.iter_mut().enumerate() {
+            let sorted = const_cge::ensemble::sorted([#(members[#member_indices][index][lane]),*]);
+            *output = #median;
+          }
+        }
+      },
+      Combine::Vote => quote! {
+        *outputs = [[0.0; LANES]; #output_count];
+        let mut member = [[0.0; LANES]; #output_count];
+        #(#lanes)*
+      }
+    };
+    Some(bank(&name, &visibility, numeric_type, recurrency_count, fingerprint.tag(), &lanes, None))
+  };
+
+  let build_info = format!(
+"- Ensemble of {member_count} networks, compiled into one `evaluate`: {member_list}
+- Outputs are combined by `{combine_name}`
+- {recurrency_statement}
+- Fingerprint (`Self::FINGERPRINT`): `{fingerprint_hex}`",
+    member_list = paths.iter().map(|path| format!("`{}`", path)).collect::<Vec<_>>().join(", "),
+    combine_name = combine.name(),
+    recurrency_statement = if recurrency_count == 0 {
+      "No recurrency detected (a ZST, `Self::evaluate` is static)".to_string()
+    } else {
+      format!("Recurrent: {} persistent states (every member's, back to back), `Self::evaluate` must take `&mut self`", recurrency_count)
+    }
+  );
+  let evaluate_documentation = format!("Evaluate every member, and combine their outputs (`combine = {}`).", combine.name());
+
+  // optionally, a test against every member in the `cge` runtime (combined the same way, in `f64`, written independently)
+  let verification = verify.map(|trials| {
+    let combination = match combine {
+      Combine::Mean => quote! {
+        (0..#output_count).map(|index| members.iter().map(|member| member[index]).sum::<f64>() / #count).collect()
+      },
+      Combine::Median => quote! {
+        (0..#output_count).map(|index| {
+          let mut sorted = members.iter().map(|member| member[index]).collect::<std::vec::Vec<f64>>();
+          sorted.sort_by(f64::total_cmp);
+          #median
+        }).collect()
+      },
+      // each member votes for its largest output (the first, on ties), ignoring `NaN`s
+      Combine::Vote => quote! {{
+        let mut votes = std::vec![0.0; #output_count];
+        for member in &members {
+          let vote = member.iter().enumerate()
+            .filter(|(_, value)| !value.is_nan())
+            .fold(None, |best: Option<(usize, f64)>, (index, &value)| match best {
+              Some((_, largest)) if value <= largest => best,
+              _ => Some((index, value))
+            })
+            .map_or(0, |(index, _)| index);
+          votes[vote] += 1.0 / #count;
+        }
+        votes
+      }}
+    };
+    let reference = Reference { sources: sources.clone(), combine: combination };
+    verification_module(&name, &format_ident!("evaluate"), &reference, recurrency_count, numeric_type, trials, None)
+  });
+
+  // cargo only rebuilds when a tracked file changes
+  let rebuild_anchors = paths.iter().map(|path| rebuild_anchor(&manifest_relative(path)));
+  let stage = stage_impl(&name, numeric_type, recurrency_count);
+
+  quote! {
+    #[doc = #build_info]
+    #[derive(Clone, Copy, Default)]
+    #item
+
+    impl #name {
+      /// The number of inputs to the ensemble (shared by every member). Provided for convenience (const).
+      pub const INPUT_COUNT:     usize = #input_count;
+
+      /// The number of outputs from the ensemble (shared by every member). Provided for convenience (const).
+      pub const OUTPUT_COUNT:    usize = #output_count;
+
+      /// The size of internal state of the ensemble (every member's, back to back). Provided for convenience (const).
+      /// - NOTE: This constant is _always available_, and will be zero for non-recurrent ensembles.
+      pub const PERSISTENT_SIZE: usize = #recurrency_count;
+
+      /// The number of networks in the ensemble. Provided for convenience (const).
+      pub const MEMBER_COUNT:    usize = #member_count;
+
+      /// A stable SHA-256 hash of every member's `FINGERPRINT` (in order), and how their outputs are combined.
+      pub const FINGERPRINT: [u8; 32] = [#(#fingerprint_bytes),*];
+
+      /// Like `Self::FINGERPRINT`, but from every member's `TOPOLOGY_HASH` (ignoring weights and biases).
+      pub const TOPOLOGY_HASH: [u8; 32] = [#(#topology_bytes),*];
+
+      #persistence_methods
+
+      /// Evaluate every member for a single input vector, without combining their outputs
+      /// (`members[m]` receives the outputs of member `m`, in the order they were listed).
+      pub fn evaluate_members(#self_argument inputs: &[#numeric_token; #input_count], members: &mut [[#numeric_token; #output_count]; #member_count]) {
+        #members_function
+      }
+
+      #[doc = #evaluate_documentation]
+      pub fn evaluate(#self_argument inputs: &[#numeric_token; #input_count], outputs: &mut [#numeric_token; #output_count]) {
+        #evaluate_function
+      }
+    }
+
+    #bank
+    #stage
+    #verification
+    #(#rebuild_anchors)*
+  }
+}
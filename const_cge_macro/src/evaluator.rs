@@ -15,11 +15,64 @@ impl ResultNames {
     self.index += 1;
     ident
   }
+}
 
-  /// Get the most recently generated name.
-  pub fn last(&self) -> Ident {
-    format_ident!("c{}", self.index.saturating_sub(1))
-  }
+/// How the arithmetic of each neuron's sum is emitted.
+#[derive(Clone, Copy)]
+pub struct Precision {
+  /// Fuse each weighting into the neuron's running sum (`mul_add`, a single rounding per term).
+  pub fma:        bool,
+
+  /// The type neuron sums are accumulated in (weights and biases are given at this precision too).
+  pub accumulate: NumericType,
+}
+
+/// A value waiting on the stack, to be summed into a neuron (or written to the outputs).
+#[derive(Clone)]
+pub enum Term {
+  /// Already weighted (in the numeric type).
+  Value(Ident),
+
+  /// `weight * value`, deferred until it is summed (`weight` is in the accumulator type, `value` in the numeric type).
+  Product { weight: TokenStream, value: TokenStream },
+
+  /// A bias (in the accumulator type).
+  Constant(TokenStream),
+}
+
+/// Expression (in the accumulator type) summing `terms` in order.
+/// - Plain `c0 + c1 + ...` when every term is already weighted.
+pub fn sum(terms: &[Term], numeric_type: NumericType, precision: Precision) -> TokenStream {
+  let accumulator = precision.accumulate.token();
+  let widen = |value: &dyn quote::ToTokens| if precision.accumulate == numeric_type { quote!(#value) } else { quote!((#value as #accumulator)) };
+  let term = |term: &Term| match term {
+    Term::Value(value)             => widen(value),
+    Term::Product { weight, value } => { let value = widen(value); quote!(#weight * #value) },
+    Term::Constant(value)          => value.clone(),
+  };
+
+  let mut terms = terms.iter();
+  let first = term(terms.next().expect("a neuron must have at least one input"));
+
+  terms.fold(first, |sum, next| match next {
+    Term::Product { weight, value } if precision.fma => {
+      let value = widen(value);
+      quote!(const_cge::activations::#accumulator::mul_add(#weight, #value, #sum))
+    },
+    _ => {
+      let next = term(next);
+      quote!(#sum + #next)
+    }
+  })
+}
+
+/// Tallies the arithmetic emitted by `evaluate`, so the cost of the generated code can be reported.
+/// - A fused multiply-add counts as one multiplication and one addition.
+#[derive(Default, Clone, Copy)]
+pub struct OperationCounts {
+  pub muls:        usize,
+  pub adds:        usize,
+  pub activations: usize,
 }
 
 /// Generate a list of low-level floating-point operations from CGE.
@@ -29,20 +82,28 @@ pub fn evaluate(
   neuron_info: &HashMap<NeuronId, NeuronInfo>, // The neuron info for the network
   range: Range<usize>,                         // Range of genes to evaluate
   neuron_update: bool,                         // Should the execution of this subnetwork update the neuron values?
-  j: bool,                                     // I do not understand this flag. You caught me.
-  root: bool,                                  // Is this the 'root' invocation of this function? used for writing into the outputs array.
+  ignore_final_neuron_weight: bool,            // Leave the root neuron of `range` unweighted? (set when evaluating the source of a forward jumper)
+  root: bool,                                  // Is this the 'root' invocation of this function? used for returning the network's outputs.
   computations: &mut Vec<TokenStream>,         // Computations tracks the actual expressions & assignments (e.g. `let c2 = (w0 * c0) + (w1 * c1);`)
   computations_end: &mut Vec<TokenStream>,     // Stuff to tack onto the end.
 
-  result_names: &mut ResultNames,          // Counts upwards and is used for making variable names
+  result_names: &mut ResultNames,              // Counts upwards and is used for making variable names
+  evaluated:    &mut HashMap<NeuronId, Ident>, // Variable holding each neuron's (unweighted) output, once it has been computed
+  operations:   &mut OperationCounts,          // Counts the multiplications, additions, and activations we emit
   recurrence_table: &HashMap<NeuronId, usize>, // A complete table mapping all neuron IDs to the index in the "persistence array"
                                                // - the persistence array is a minimal set of floats that are needed to support the recurrent behavior of the network
                                                // - if there are 6 "backwards" connections, then the persistence array will need 6 floats, and this hashmap will contain
                                                //   6 entries.
   numeric_type: NumericType,                   // The _target_ numeric type to be used in the generated code.
+  precision:    Precision,                     // How neuron sums are accumulated (the plain default: unfused, in `numeric_type`)
   activation:   TokenStream,                   // Path to optimized activation function (e.g. `const_cge::activations::f32::relu`)
-) -> Option<usize> {
-  let mut stack = Stack::new();
+) -> Option<Vec<TokenStream>> {
+  let mut stack = Stack::<Term>::new();
+
+  // weightings are deferred into each neuron's sum, unless sums are plain arithmetic in the numeric type
+  let deferred = precision.fma || precision.accumulate != numeric_type;
+  let weight_of = |weight: f64| precision.accumulate.naive_conversion(weight);
+  let numeric_token = numeric_type.token();
   
   for gene_index in range.clone().rev() {
     match &genome[gene_index] {
@@ -50,12 +111,18 @@ pub fn evaluate(
         // If the gene is an input, push its value multiplied by the inputs weight onto
         // the stack
         let input_id = input.id().as_usize();
-        let input_weighting = numeric_type.naive_conversion(input.weight());
-        let result_id = result_names.advance();
+        operations.muls += 1;
 
         // the input ID of `j` corresponds to the `j`th element of input buffer
+        if deferred {
+          stack.push(Term::Product { weight: weight_of(input.weight()), value: quote!(inputs[#input_id]) });
+          continue;
+        }
+
+        let input_weighting = numeric_type.naive_conversion(input.weight());
+        let result_id = result_names.advance();
         computations.push(quote! { let #result_id = #input_weighting * inputs[#input_id]; });
-        stack.push(result_id);
+        stack.push(Term::Value(result_id));
       },
       Gene::Neuron(n) => {
         // If the gene is a neuron, pop a number (the neurons input count) of inputs
@@ -74,10 +141,16 @@ pub fn evaluate(
         // reverse the order of sum to provide a perfect bitwise match with `cge`.
         inputs.reverse();
 
+        let sum = sum(&inputs, numeric_type, precision);
+        let narrowed = if precision.accumulate == numeric_type { quote!(#result_id) } else { quote!(#result_id as #numeric_token) };
         computations.push(quote! {
-          let #result_id = #(#inputs)+*;            // sum the inputs for neuron ##neuron_id
-          let #result_id = #activation(#result_id); // apply activation function
+          let #result_id = #sum;                    // sum the inputs for neuron ##neuron_id
+          let #result_id = #activation(#narrowed);  // apply activation function
         });
+        operations.adds += input_count - 1;
+        operations.activations += 1;
+
+        evaluated.insert(neuron_id, result_id.clone());
 
         if neuron_update {
           if let Some(index) = recurrence_table.get(&neuron_id) {
@@ -87,46 +160,67 @@ pub fn evaluate(
           }
         }
 
-        // when j flag is set, do not include weight of last neuron link as jump forward has a different weight
-        if !j || gene_index != range.start {
+        // a forward jumper reads the neuron's output through its own weight, instead of the neuron's weight
+        if !ignore_final_neuron_weight || gene_index != range.start {
           // otherwise use regular weight of connection in stack
-          let weight = numeric_type.naive_conversion(weight);
-          let new_result_id = result_names.advance();
-          computations.push(quote! { let #new_result_id = #result_id * #weight; });
-          stack.push(new_result_id);
+          operations.muls += 1;
+          if deferred {
+            stack.push(Term::Product { weight: weight_of(weight), value: quote!(#result_id) });
+          } else {
+            let weight = numeric_type.naive_conversion(weight);
+            let new_result_id = result_names.advance();
+            computations.push(quote! { let #new_result_id = #result_id * #weight; });
+            stack.push(Term::Value(new_result_id));
+          }
         } else {
-          stack.push(result_id);
+          stack.push(Term::Value(result_id));
         }
       },
       Gene::ForwardJumper(f) => {
-        // If the gene is a forward jumper, evaluate the subnetwork starting at the
-        // neuron with id of the jumper, and push the result multiplied by the jumpers
-        // weight onto the stack
+        // If the gene is a forward jumper, push the current (unweighted) output of its source neuron,
+        // multiplied by the jumper's weight, onto the stack (this is exactly what the `cge` runtime does).
+        // - if the source neuron has already been computed during this evaluation, reuse its output.
+        // - otherwise, evaluate the subnetwork rooted at the source neuron right here, leaving the root unweighted.
+        //   the subnetwork may itself contain forward jumpers, these recurse in the same way.
+        //   (recurrent state is only updated by the top-level pass, which will reach these neurons again)
+        let id = f.source_id();
+
+        if !evaluated.contains_key(&id) {
+          let subnetwork_range = neuron_info
+            .get(&id)
+            .unwrap_or_else(|| panic!("Corrupt CGE: encountered a forward jumper (gene {}) with an invalid neuron ID ({})", gene_index, id.as_usize()))
+            .subgenome_range();
+
+          evaluate(
+            genome,
+            neuron_info,
+            subnetwork_range,
+            false,
+            true,
+            false,
+            computations,
+            computations_end,
+            result_names,
+            evaluated,
+            operations,
+            recurrence_table,
+            numeric_type,
+            precision,
+            activation.clone()
+          );
+        }
+
+        let subnetwork_result_id = &evaluated[&id];
+        operations.muls += 1;
+        if deferred {
+          stack.push(Term::Product { weight: weight_of(f.weight()), value: quote!(#subnetwork_result_id) });
+          continue;
+        }
+
         let weight = numeric_type.naive_conversion(f.weight());
-        let id = f.source_id(); // THIS IS A GUESS, VERIFY WITH OWEN
-        let subnetwork_range = neuron_info[&id].subgenome_range();
-
-        // set j flag to true so the neuron does not include it's regular link weight
-        // otherwise the values will be off by whatever factor the neuron weight is
-        evaluate(
-          genome,
-          neuron_info,
-          subnetwork_range,
-          false,
-          true,
-          false,
-          computations,
-          computations_end,
-          result_names,
-          recurrence_table,
-          numeric_type,
-          activation.clone()
-        );
-
-        let subnetwork_result_id = result_names.last();
         let weighted_result_id = result_names.advance();
         computations.push(quote! { let #weighted_result_id = #subnetwork_result_id * #weight; });
-        stack.push(weighted_result_id);
+        stack.push(Term::Value(weighted_result_id));
       },
       Gene::RecurrentJumper(r) => {
         // If the gene is a recurrent jumper, push the previous value of the neuron
@@ -135,6 +229,12 @@ pub fn evaluate(
           .get(&r.source_id())
           .unwrap_or_else(|| panic!("Corrupt CGE: encountered a recurrent connection (gene {}) with an invalid neuron ID ({})", gene_index, r.source_id().as_usize()));
 
+        operations.muls += 1;
+        if deferred {
+          stack.push(Term::Product { weight: weight_of(r.weight()), value: quote!(self.persistence[#persistence_index]) });
+          continue;
+        }
+
         // this is useless code (`let c137 = self.persistence[2];`)
         // however it lets us keep the same process consistent & LLVM will optimize it out I'm p sure
         let result_id = result_names.advance();
@@ -142,11 +242,16 @@ pub fn evaluate(
 
         // access persistence, apply weighting
         computations.push(quote! { let #result_id = #weight * self.persistence[#persistence_index]; });
-        stack.push(result_id);
+        stack.push(Term::Value(result_id));
       },
       Gene::Bias(b) => {
         // If the gene is a bias input, push the bias constant multiplied by the genes
         // weight onto the stack
+        if deferred {
+          stack.push(Term::Constant(weight_of(b.value())));
+          continue;
+        }
+
         let bias = numeric_type.naive_conversion(b.value());
 
         // this is more junk rustc will const-propagate / LLVM will optimize (`let c137 = -0.02302234`);
@@ -154,23 +259,21 @@ pub fn evaluate(
         //       An immutible literal /has the same properties as a constant/, but idk if rustc treats them the identically or not.
         let result_id = result_names.advance();
         computations.push(quote! { let #result_id = #bias; });
-        stack.push(result_id);
+        stack.push(Term::Value(result_id));
       }
     }
   }
 
   // now the stack contains the identifiers (variable names) of the result of the network.
-  // - we must write them into output buffer rather than return them
+  // - hand back their values, the caller decides where they go (usually `outputs[index] = value;`)
   if root {
-    let output_count = stack.data.len();
-
-    for (index, identifier) in stack.data.iter().enumerate() {
-      computations.push(quote! {
-        outputs[#index] = #identifier; // store network output in output buffer
-      });
-    }
+    let outputs = stack.data.iter().map(|term| match term {
+      Term::Value(identifier) => quote!(#identifier),
+      term if precision.accumulate == numeric_type => sum(std::slice::from_ref(term), numeric_type, precision),
+      term => { let value = sum(std::slice::from_ref(term), numeric_type, precision); quote!((#value) as #numeric_token) }
+    }).collect();
 
-    Some(output_count)
+    Some(outputs)
   } else {
     None
   }
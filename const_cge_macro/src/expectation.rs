@@ -0,0 +1,135 @@
+use cge::{Network, WithRecurrentState};
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, format_ident};
+use syn::Expr;
+use crate::{numeric_type::NumericType, mode::{parse_float, float_token}, verification::Approximation};
+
+/// One golden vector: the inputs, and the outputs the network must produce for them.
+pub type Expectation = (Vec<f64>, Vec<f64>);
+
+/// Parse `[([inputs...], [outputs...]), ...]`
+pub fn parse_expectations(expr: &Expr) -> syn::Result<Vec<Expectation>> {
+  let parse_vector = |expr: &Expr| match expr {
+    Expr::Array(array) => array.elems.iter().map(parse_float).collect::<syn::Result<Vec<f64>>>(),
+    _ => Err(syn::Error::new_spanned(expr, "Expected an array of numbers, like `[1.0, 0.5]`."))
+  };
+
+  match expr {
+    Expr::Array(array) => array.elems
+      .iter()
+      .map(|pair| match pair {
+        Expr::Tuple(tuple) if tuple.elems.len() == 2 => Ok((parse_vector(&tuple.elems[0])?, parse_vector(&tuple.elems[1])?)),
+        _ => Err(syn::Error::new_spanned(pair, "Expected an `(inputs, outputs)` pair, like `([1.0, 0.5], [0.73])`."))
+      })
+      .collect(),
+    _ => Err(syn::Error::new_spanned(expr, "Expected a list of `(inputs, outputs)` pairs, like `expect = [([1.0, 0.5], [0.73])]`."))
+  }
+}
+
+/// Parse an `expect_file`: a JSON list of `[inputs, outputs]` pairs (e.g. `[[[1.0, 0.5], [0.73]]]`).
+pub fn parse_expectation_file(path: &str, data: &str) -> Vec<Expectation> {
+  serde_json::from_str(data)
+    .unwrap_or_else(|e| panic!("Failed to parse `expect_file` `{}` ({}). Expected a JSON list of `[inputs, outputs]` pairs, like `[[[1.0, 0.5], [0.73]]]`.", path, e))
+}
+
+/// `[([inputs...], [outputs...]), ...]`, as parsed by `parse_expectations`
+pub fn expectations_token(expectations: &[Expectation]) -> TokenStream {
+  let pairs = expectations.iter().map(|(inputs, outputs)| {
+    let (inputs, outputs) = (inputs.iter().map(|&x| float_token(x)), outputs.iter().map(|&x| float_token(x)));
+    quote!(([#(#inputs),*], [#(#outputs),*]))
+  });
+
+  quote!([#(#pairs),*])
+}
+
+/// Check the golden vectors against the `cge` runtime (our reference interpreter), at compile time.
+/// - Recurrent networks see the vectors as consecutive steps, from the initial (all-zero) state.
+pub fn check_expectations(source: &str, expectations: &[Expectation], numeric_type: NumericType) {
+  let (mut network, _, _) = Network::<f64>::load_str::<()>(source, WithRecurrentState(false))
+    .unwrap_or_else(|e| panic!("Failed to load network ({})", e));
+
+  let (input_count, output_count) = (network.num_inputs(), network.num_outputs());
+  let tolerance = numeric_type.tolerance();
+
+  for (index, (inputs, expected)) in expectations.iter().enumerate() {
+    if inputs.len() != input_count { panic!("`expect` vector {} has {} inputs, but the network has {}.", index, inputs.len(), input_count); }
+    if expected.len() != output_count { panic!("`expect` vector {} has {} outputs, but the network has {}.", index, expected.len(), output_count); }
+
+    let outputs = network.evaluate(inputs).expect("`cge` runtime rejected the inputs");
+    for (output, (&actual, &expected)) in outputs.iter().zip(expected).enumerate() {
+      if (actual - expected).abs() > tolerance * (1.0 + expected.abs()) {
+        panic!("Network fails `expect` vector {} (inputs {:?}): output {} is {}, but {} was expected.", index, inputs, output, actual, expected);
+      }
+    }
+  }
+}
+
+/// Generate a `#[cfg(test)]` module which checks the compiled `evaluate` against the golden vectors
+/// (within the tolerance of the numeric type).
+/// - `evaluate` is the compiled function to check (`evaluate_raw` if the network has an output head,
+///   golden vectors describe the network itself).
+/// - An `approximation` (`mode = lut(...)`) is allowed its measured error, like `verify` does, and every vector must lie within its ranges.
+pub fn expectation_module(
+  name:             &Ident,
+  evaluate:         &Ident,
+  expectations:     &[Expectation],
+  recurrency_count: usize,
+  numeric_type:     NumericType,
+  approximation:    Option<&Approximation>
+) -> TokenStream {
+  let module_name = format_ident!("__const_cge_expect_{}", name);
+  let test_name = format_ident!("{}_meets_expectations", name.to_string().to_lowercase());
+  let numeric_token = numeric_type.token();
+  let tolerance = numeric_type.tolerance();
+
+  // inputs outside the ranges are clamped, the approximation says nothing about them
+  let allowance = match approximation {
+    Some(approximation) => {
+      for (index, (inputs, _)) in expectations.iter().enumerate() {
+        if !approximation.contains(inputs) {
+          panic!("`expect` vector {} (inputs {:?}) lies outside the `mode = lut(...)` ranges {:?}, where the lookup table only approximates the network.", index, inputs, approximation.ranges);
+        }
+      }
+      approximation.allowance()
+    },
+    None => 0.0
+  };
+
+  // consecutive steps of one instance for recurrent networks
+  let (instance, evaluate) = if recurrency_count == 0 {
+    (quote!(), quote!(#name::#evaluate))
+  } else {
+    (quote!(let mut net = #name::default();), quote!(net.#evaluate))
+  };
+
+  let expectations = expectations_token(expectations);
+
+  quote! {
+    #[cfg(test)]
+    #[allow(non_snake_case)]
+    mod #module_name {
+      use super::#name;
+
+      #[test]
+      fn #test_name() {
+        let expectations: &[([f64; #name::INPUT_COUNT], [f64; #name::OUTPUT_COUNT])] = &#expectations;
+        #instance
+
+        for (index, (inputs, expected)) in expectations.iter().enumerate() {
+          let inputs = inputs.map(|x| x as #numeric_token);
+          let mut outputs = [0.0; #name::OUTPUT_COUNT];
+          #evaluate(&inputs, &mut outputs);
+
+          for (output, (&compiled, &expected)) in outputs.iter().zip(expected).enumerate() {
+            let compiled = compiled as f64;
+            assert!(
+              (compiled - expected).abs() <= #tolerance * (1.0 + expected.abs()) + #allowance,
+              "`expect` vector {}, output {}: compiled network produced {}, {} was expected",
+              index, output, compiled, expected
+            );
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,108 @@
+use cge::{Network, gene::Gene, Activation};
+use sha2::{Sha256, Digest};
+use crate::numeric_type::NumericType;
+
+/// Stable hashes identifying a network.
+pub struct Fingerprint {
+  /// SHA-256 of the canonicalized genome (structure and every parameter), activation, and numeric type.
+  pub full:     [u8; 32],
+
+  /// SHA-256 of the canonicalized genome structure, activation, and numeric type (ignoring weights and biases).
+  pub topology: [u8; 32],
+}
+
+impl Fingerprint {
+  /// Tags the type of snapshots of recurrent state (`const_cge::State`): the first 8 bytes of `full`.
+  pub fn tag(&self) -> u64 {
+    u64::from_le_bytes(self.full[..8].try_into().unwrap())
+  }
+
+  /// `full` as lowercase hex (for documentation)
+  pub fn hex(&self) -> String {
+    self.full.iter().map(|byte| format!("{:02x}", byte)).collect()
+  }
+}
+
+/// Fingerprint the network.
+/// - Canonical: only the genome, activation, and numeric type matter (not metadata, formatting, or extra data in the CGE file).
+/// - Independent of how the network is compiled (`mode`, `fma`, ...), these do not change the meaning of the network or its recurrent state.
+/// - The encoding is versioned, and must never change for a given version (users pin these values).
+pub fn fingerprint(network: &Network<f64>, numeric_type: NumericType) -> Fingerprint {
+  let hash = |include_parameters: bool| -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    let write_usize = |hasher: &mut Sha256, value: usize| hasher.update((value as u64).to_le_bytes());
+    let write_f64 = |hasher: &mut Sha256, value: f64| if include_parameters { hasher.update(value.to_bits().to_le_bytes()) };
+
+    hasher.update(if include_parameters { &b"const_cge fingerprint v1\0"[..] } else { &b"const_cge topology v1\0"[..] });
+    hasher.update(match numeric_type {
+      NumericType::Float64 => [0],
+      NumericType::Float32 => [1],
+    });
+    hasher.update([match network.activation() {
+      Activation::Linear       => 0,
+      Activation::UnitStep     => 1,
+      Activation::Relu         => 2,
+      Activation::Sign         => 3,
+      Activation::Sigmoid      => 4,
+      Activation::Tanh         => 5,
+      Activation::SoftSign     => 6,
+      Activation::BentIdentity => 7,
+    }]);
+
+    let genome = network.genome();
+    write_usize(&mut hasher, genome.len());
+
+    for gene in genome {
+      match gene {
+        Gene::Neuron(n) => {
+          hasher.update([0]);
+          write_usize(&mut hasher, n.id().as_usize());
+          write_usize(&mut hasher, n.num_inputs());
+          write_f64(&mut hasher, n.weight());
+        },
+        Gene::Input(i) => {
+          hasher.update([1]);
+          write_usize(&mut hasher, i.id().as_usize());
+          write_f64(&mut hasher, i.weight());
+        },
+        Gene::Bias(b) => {
+          hasher.update([2]);
+          write_f64(&mut hasher, b.value());
+        },
+        Gene::ForwardJumper(f) => {
+          hasher.update([3]);
+          write_usize(&mut hasher, f.source_id().as_usize());
+          write_f64(&mut hasher, f.weight());
+        },
+        Gene::RecurrentJumper(r) => {
+          hasher.update([4]);
+          write_usize(&mut hasher, r.source_id().as_usize());
+          write_f64(&mut hasher, r.weight());
+        },
+      }
+    }
+
+    hasher.finalize().into()
+  };
+
+  Fingerprint { full: hash(true), topology: hash(false) }
+}
+
+/// Fingerprint an ensemble, from its members' fingerprints (in order) and how their outputs are combined.
+/// - Versioned like `fingerprint`, and just as stable.
+pub fn ensemble_fingerprint(members: &[Fingerprint], combine: &str) -> Fingerprint {
+  let hash = |prefix: &[u8], member: fn(&Fingerprint) -> &[u8; 32]| -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(prefix);
+    hasher.update((combine.len() as u64).to_le_bytes());
+    hasher.update(combine.as_bytes());
+    hasher.update((members.len() as u64).to_le_bytes());
+    for fingerprint in members { hasher.update(member(fingerprint)); }
+    hasher.finalize().into()
+  };
+
+  Fingerprint {
+    full:     hash(b"const_cge ensemble fingerprint v1\0", |fingerprint| &fingerprint.full),
+    topology: hash(b"const_cge ensemble topology v1\0", |fingerprint| &fingerprint.topology),
+  }
+}
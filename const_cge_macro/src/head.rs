@@ -0,0 +1,200 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, format_ident};
+use syn::{Expr, ExprCall, ImplItemMethod, Visibility};
+use crate::{numeric_type::NumericType, mode::{parse_float, float_token}};
+
+/// Post-processing applied to the outputs of `evaluate` (`head = ...`)
+#[derive(Clone)]
+pub enum Head {
+  /// Outputs become probabilities (positive, summing to one).
+  Softmax,
+
+  /// Outputs become a one-hot encoding of the largest output.
+  Argmax,
+
+  /// Outputs are clamped into `[lo, hi]`.
+  Clamp { lo: f64, hi: f64 }
+}
+
+impl Head {
+  pub const VARIANTS_LIST: &'static str = "softmax, argmax, clamp(lo, hi)";
+
+  /// Parse the value of `head = ...`
+  pub fn parse(expr: &Expr) -> syn::Result<Self> {
+    let invalid = || syn::Error::new_spanned(expr, format!("Invalid `head`. Please use one of {{ {} }}.", Head::VARIANTS_LIST));
+
+    match expr {
+      Expr::Path(p) if p.path.is_ident("softmax") => Ok(Head::Softmax),
+      Expr::Path(p) if p.path.is_ident("argmax")  => Ok(Head::Argmax),
+      Expr::Call(ExprCall { func, args, .. }) if matches!(&**func, Expr::Path(p) if p.path.is_ident("clamp")) => {
+        if args.len() != 2 { return Err(syn::Error::new_spanned(expr, "`clamp` takes two bounds, like `clamp(-1.0, 1.0)`.")); }
+
+        let (lo, hi) = (parse_float(&args[0])?, parse_float(&args[1])?);
+        if lo > hi { return Err(syn::Error::new_spanned(expr, "`clamp(lo, hi)` requires `lo <= hi`.")); }
+
+        Ok(Head::Clamp { lo, hi })
+      },
+      _ => Err(invalid())
+    }
+  }
+
+  /// Provides the tokens of the head, suitable for interpolation as the value of `head = ...`
+  pub fn token(&self) -> TokenStream {
+    match self {
+      Head::Softmax => quote!(softmax),
+      Head::Argmax  => quote!(argmax),
+      Head::Clamp { lo, hi } => {
+        let (lo, hi) = (float_token(*lo), float_token(*hi));
+        quote!(clamp(#lo, #hi))
+      }
+    }
+  }
+
+  /// The head as written (e.g. `clamp(-1.0, 1.0)`), for documentation
+  pub fn name(&self) -> String {
+    match self {
+      Head::Softmax => "softmax".into(),
+      Head::Argmax  => "argmax".into(),
+      Head::Clamp { lo, hi } => format!("clamp({:?}, {:?})", lo, hi)
+    }
+  }
+
+  /// The post-processing of `outputs` (a `&mut [T; N]` in scope)
+  pub fn apply(&self, numeric_type: NumericType) -> TokenStream {
+    let numeric_token = numeric_type.token();
+
+    match self {
+      Head::Softmax => quote!(const_cge::head::softmax(outputs, const_cge::activations::#numeric_token::exp);),
+      Head::Argmax  => quote!(const_cge::head::one_hot(outputs, 0.0, 1.0);),
+      Head::Clamp { lo, hi } => {
+        let (lo, hi) = (float_token(*lo), float_token(*hi));
+        quote!(const_cge::head::clamp(outputs, #lo, #hi);)
+      }
+    }
+  }
+}
+
+/// Rename the generated `evaluate` to `evaluate_raw`, and add an `evaluate` which applies the head to its outputs.
+pub fn with_head(
+  evaluate_function: TokenStream,
+  head:              &Head,
+  numeric_type:      NumericType,
+  recurrency_count:  usize,
+  input_count:       usize,
+  output_count:      usize
+) -> TokenStream {
+  let mut raw: ImplItemMethod = syn::parse2(evaluate_function).expect("Generated `evaluate` is not a method");
+  raw.sig.ident = format_ident!("evaluate_raw");
+
+  let head_name = head.name();
+  let raw_documentation = format!("Evaluate the network, *without* its output head (`head = {}`).", head_name);
+  let documentation = format!("Evaluate the network for a single input vector, then apply its output head (`head = {}`).", head_name);
+
+  let numeric_token = numeric_type.token();
+  let (self_argument, evaluate_raw) = if recurrency_count == 0 {
+    (quote!(), quote!(Self::evaluate_raw))
+  } else {
+    (quote!(&mut self,), quote!(self.evaluate_raw))
+  };
+  let apply = head.apply(numeric_type);
+
+  quote! {
+    #[doc = #raw_documentation]
+    ///
+    #raw
+
+    #[doc = #documentation]
+    /// - `Self::evaluate_raw` returns the outputs before the head.
+    pub fn evaluate(#self_argument inputs: &[#numeric_token; #input_count], outputs: &mut [#numeric_token; #output_count]) {
+      #evaluate_raw(inputs, outputs);
+      #apply
+    }
+  }
+}
+
+/// The enum variant for a label (`"walk"` is `Walk`, `"run_fast"` is `RunFast`, `"7"` is `_7`)
+fn label_variant(label: &str) -> Ident {
+  let variant: String = label
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|word| !word.is_empty())
+    .map(|word| {
+      let mut chars = word.chars();
+      let first = chars.next().unwrap();
+      first.to_uppercase().chain(chars).collect::<String>()
+    })
+    .collect();
+
+  let variant = match variant.chars().next() {
+    None => panic!("Label `{:?}` has no letters or digits, so cannot name an enum variant.", label),
+    Some(c) if c.is_numeric() => format!("_{}", variant),
+    Some(_) => variant
+  };
+
+  syn::parse_str(&variant).unwrap_or_else(|_| panic!("Label `{:?}` becomes `{}`, which is not a valid enum variant.", label, variant))
+}
+
+/// Generate `<Name>Label` (one variant per output, from `labels = [...]`) and `Name::classify`, which returns the label of the largest output.
+/// - The number of labels has already been checked against the number of outputs.
+pub fn label_enum(name: &Ident, visibility: &Visibility, labels: &[String], recurrency_count: usize, numeric_type: NumericType) -> (TokenStream, TokenStream) {
+  let label = format_ident!("{}Label", name);
+  let variants: Vec<Ident> = labels.iter().map(|label| label_variant(label)).collect();
+  for (index, variant) in variants.iter().enumerate() {
+    if let Some(other) = variants[..index].iter().position(|other| other == variant) {
+      panic!("Labels `{:?}` and `{:?}` both become the enum variant `{}`.", labels[other], labels[index], variant);
+    }
+  }
+
+  let label_count = labels.len();
+  let indices = 0..label_count;
+  let documentation = format!("The labels of the outputs of [`{}`], in order (see `{}::classify`).", name, name);
+  let numeric_token = numeric_type.token();
+
+  let label_enum = quote! {
+    #[doc = #documentation]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #visibility enum #label {
+      #( #[doc = #labels] #variants ),*
+    }
+
+    impl #label {
+      /// Every label, in output order.
+      pub const ALL: [Self; #label_count] = [#(Self::#variants),*];
+
+      /// The index of the output this label belongs to.
+      pub const fn index(self) -> usize { self as usize }
+
+      /// The label, as declared (`labels = [...]`).
+      pub const fn as_str(self) -> &'static str {
+        match self { #(Self::#variants => #labels),* }
+      }
+
+      /// The label of output `index` (if there is one).
+      pub const fn from_index(index: usize) -> Option<Self> {
+        match index { #(#indices => Some(Self::#variants),)* _ => None }
+      }
+    }
+
+    impl ::core::fmt::Display for #label {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.write_str(self.as_str())
+      }
+    }
+  };
+
+  let (self_argument, evaluate) = if recurrency_count == 0 {
+    (quote!(), quote!(Self::evaluate))
+  } else {
+    (quote!(&mut self,), quote!(self.evaluate))
+  };
+
+  let classify_function = quote! {
+    /// Evaluate the network, and return the label of its largest output.
+    pub fn classify(#self_argument inputs: &[#numeric_token; #name::INPUT_COUNT]) -> #label {
+      let mut outputs = [0.0; #name::OUTPUT_COUNT];
+      #evaluate(inputs, &mut outputs);
+      #label::ALL[const_cge::head::argmax(&outputs)]
+    }
+  };
+
+  (label_enum, classify_function)
+}
@@ -0,0 +1,69 @@
+use cge::{Network, Activation};
+use crate::{macro_core::Config, recurrency::RecurrencyConstraint};
+
+/// Every activation, as spelled in CGE files (and in `activation = ...`)
+const ACTIVATIONS: [(&str, Activation); 8] = [
+  ("linear",       Activation::Linear),
+  ("unitstep",     Activation::UnitStep),
+  ("relu",         Activation::Relu),
+  ("sign",         Activation::Sign),
+  ("sigmoid",      Activation::Sigmoid),
+  ("tanh",         Activation::Tanh),
+  ("softsign",     Activation::SoftSign),
+  ("bentidentity", Activation::BentIdentity),
+];
+
+pub const ACTIVATIONS_LIST: &str = "linear, unitstep, relu, sign, sigmoid, tanh, softsign, bentidentity";
+
+pub fn parse_activation(name: &str) -> Option<Activation> {
+  ACTIVATIONS.iter().find(|(spelling, _)| *spelling == name).map(|&(_, activation)| activation)
+}
+
+pub fn activation_name(activation: Activation) -> &'static str {
+  ACTIVATIONS.iter().find(|(_, a)| *a == activation).unwrap().0
+}
+
+/// Check the loaded network against the interface the invocation asserts (`#[recurrent]` / `#[nonrecurrent]`,
+/// `inputs = N`, `outputs = N`, `max_state = N`, `activation = ...`).
+/// - Fails at the attribute, rather than at some distant array length mismatch, when a network (e.g. from a netcrate) changes.
+pub fn check_interface(
+  config:                &Config,
+  recurrency_constraint: RecurrencyConstraint,
+  network:               &Network<f64>,
+  recurrency_count:      usize
+) {
+  match recurrency_constraint {
+    // no constraint
+    RecurrencyConstraint::DontCare  => {},
+
+    // require recurrency
+    RecurrencyConstraint::Required  => if recurrency_count == 0 { panic!("Network is not recurrent (it was demanded)."); },
+
+    // forbid recurrency
+    RecurrencyConstraint::Forbidden => if recurrency_count != 0 { panic!("Network is recurrent (it was forbidden)."); }
+  }
+
+  let mismatch = |argument: &str, asserted: &dyn std::fmt::Display, actual: String| -> ! {
+    panic!("Network interface mismatch: `{} = {}` was asserted, but the network has {} (has the network changed?).", argument, asserted, actual)
+  };
+
+  if let Some(inputs) = config.inputs {
+    if network.num_inputs() != inputs { mismatch("inputs", &inputs, format!("{} inputs", network.num_inputs())); }
+  }
+
+  if let Some(outputs) = config.outputs {
+    if network.num_outputs() != outputs { mismatch("outputs", &outputs, format!("{} outputs", network.num_outputs())); }
+  }
+
+  if let Some(max_state) = config.max_state {
+    if recurrency_count > max_state { mismatch("max_state", &max_state, format!("{} elements of recurrent state", recurrency_count)); }
+  }
+
+  if let Some(activation) = config.activation {
+    if network.activation() != activation { mismatch("activation", &activation_name(activation), format!("`{}` activation", activation_name(network.activation()))); }
+  }
+
+  if let Some(ref labels) = config.labels {
+    if labels.len() != network.num_outputs() { panic!("`labels` has {} labels, but the network has {} outputs (one label per output).", labels.len(), network.num_outputs()); }
+  }
+}
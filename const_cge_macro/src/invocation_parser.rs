@@ -1,6 +1,8 @@
-use proc_macro2::Ident;
-use syn::{Token, ExprLit, TypePath, Lit, Expr, ExprPath};
-use crate::{macro_core::{Config, CgeType}, numeric_type::NumericType};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use std::path::{Path, PathBuf};
+use syn::{Token, ExprLit, ExprAssign, TypePath, Lit, LitInt, LitBool, LitStr, Expr, ExprPath};
+use crate::{macro_core::{Config, CgeType}, numeric_type::NumericType, mode::{Mode, parse_float, float_token}, interface::{parse_activation, activation_name, ACTIVATIONS_LIST}, expectation::{parse_expectations, parse_expectation_file, expectations_token}, head::Head};
 
 impl syn::parse::Parse for crate::macro_core::Config {
   fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
@@ -15,9 +17,9 @@ impl syn::parse::Parse for crate::macro_core::Config {
           // if that fails, I will try to treat it as data.
 
           let string = string.value();
-          match std::path::PathBuf::try_from(string.clone()) {
-            // it parses as a valid path AND it exists
-            Ok(p) if p.exists() => CgeType::File(string),
+          match manifest_relative(&string) {
+            // it parses as a valid path AND it exists (relative to the invoking crate)
+            p if p.exists() => CgeType::File(string),
 
             // it either isn't a valid path, or it could be, but that file doesn't exist.
             // so we will assume it is a `Direct` CGE string
@@ -25,46 +27,252 @@ impl syn::parse::Parse for crate::macro_core::Config {
           }
         },
 
+        // invoked like #[network(file = "path/to/file.cge")], #[network(data = "literal cge data")], or #[network(env = "MODEL_PATH")]
+        Ok(Expr::Assign(ExprAssign { left, right, .. })) => {
+          let kind = match *left {
+            Expr::Path(ExprPath { ref path, .. }) if path.get_ident().is_some() => path.get_ident().unwrap().to_string(),
+            _ => panic!("Expected the CGE source first, one of {{ {} }}.", CgeType::KINDS_LIST)
+          };
+          let string = match *right {
+            Expr::Lit(ExprLit { lit: Lit::Str(ref string), .. }) => string.value(),
+            _ => panic!("Expected a string after `{} = `.", kind)
+          };
+
+          match kind.as_ref() {
+            "file" => {
+              let path = manifest_relative(&string);
+              if !path.exists() { panic!("CGE file `{}` does not exist (looked for `{}`, paths are relative to the crate root).", string, path.display()); }
+              CgeType::File(string)
+            },
+            "data" => CgeType::Direct(string),
+            "env" => {
+              let path = std::env::var(&string)
+                .unwrap_or_else(|_| panic!("Environment variable `{}` is not set (`env = \"{}\"` names the CGE file to compile, at build time).", string, string));
+
+              if !manifest_relative(&path).exists() { panic!("CGE file `{}` (from `{}`) does not exist (looked for `{}`, paths are relative to the crate root).", path, string, manifest_relative(&path).display()); }
+              CgeType::Environment { variable: string, path }
+            },
+            _ => panic!("Unknown CGE source `{}`. Expected one of {{ {} }}.", kind, CgeType::KINDS_LIST)
+          }
+        },
+
         // invoked like #[network(ocr_network)]
         Ok(Expr::Path(ExprPath { path, .. })) => CgeType::Module(path),
 
         // abort compilation with error
-        _ => panic!("Expected either a string path to a CGE file, or a module name. Make sure the CGE path string or module path is the first argument, like: `#[network(\"path/to/file.cge\")]` or `#[network(some_netcrate)]`.")
+        _ => panic!("Expected either a string path to a CGE file, or a module name. Make sure the CGE path string or module path is the first argument, like: `#[network(\"path/to/file.cge\")]`, `#[network(file = \"path/to/file.cge\")]` or `#[network(some_netcrate)]`.")
       }
     };
 
-    // manually parse remaining arguments.
-    let numeric_type = {
-      if let Some(_) = input.parse::<Option<Token![,]>>().ok() {
-        if let Some(arg_name) = input.parse::<Ident>().ok() {
-          if arg_name != "numeric_type" { return Err(syn::Error::new_spanned(arg_name, "Nonsense attribute `{}`. Expected either `numeric_type` or nothing.")); }
-
-          // discard '='
-          let _: Token![=] = input.parse()
-            .unwrap_or_else(|_| panic!("Expected '=' after `numeric_type` argument."));
-
-          // parse the numeric type
-          let name = input.parse::<TypePath>()
-            .unwrap_or_else(|_| panic!("Expected type after `numeric_type = `. Please choose one of {{ {} }}", NumericType::VARIANTS_LIST));
-          let type_ident = name.path.get_ident()
-            .unwrap_or_else(|| panic!("Invalid `numeric_type`. Please use one of {{ {} }}.", NumericType::VARIANTS_LIST));
-
-          match type_ident.to_string().as_ref() {
-            "f32" => NumericType::Float32,
-            "f64" => NumericType::Float64,
-            _ => panic!("Invalid `numeric_type`. Please use one of {{ {} }}.", NumericType::VARIANTS_LIST)
-          }
-        } else {
-          // assume f64 if not specified
-          NumericType::Float64
-        }
-      } else {
-        // assume f64 if not specified
-        NumericType::Float64
-      }
+    // manually parse remaining arguments (`name = value`, in any order).
+    let mut config = Config::new(cge);
+
+    while input.parse::<Option<Token![,]>>()?.is_some() {
+      // tolerate a trailing comma
+      if input.is_empty() { break; }
+
+      let arg_name = input.parse::<Ident>()?;
+
+      // discard '='
+      let _: Token![=] = input.parse()
+        .unwrap_or_else(|_| panic!("Expected '=' after `{}` argument.", arg_name));
+
+      config.parse_argument(&arg_name, input)?;
+    }
+
+    Ok(config)
+  }
+}
+
+impl Config {
+  /// The config of an invocation which sets no arguments.
+  pub fn new(cge: CgeType) -> Self {
+    Config {
+      cge,
+      numeric_type: NumericType::Float64, // assume f64 if not specified
+      verify:       None,
+      mode:         Mode::Arithmetic,
+      fma:          false,
+      accumulate:   None,                 // accumulate in the numeric type if not specified
+      inputs:       None,                 // nothing asserted about the interface if not specified
+      outputs:      None,
+      max_state:    None,
+      activation:   None,
+      expect:       None,                 // no golden vectors if not specified
+      expect_file:  None,
+      input_scale:  None,                 // inputs are used as-is if not specified (or given in the CGE file)
+      input_offset: None,
+      head:         None,                 // outputs are returned as-is if not specified
+      labels:       None,
+      input_names:  None,                 // taken from the CGE file (if any) if not specified
+      output_names: None,
+      specified:    vec![],
+    }
+  }
+
+  /// Parse the value of the argument `arg_name` (the `name = ` has already been parsed).
+  /// - Arguments given more than once take the last value (so defaults can be overridden by appending).
+  pub fn parse_argument(&mut self, arg_name: &Ident, input: syn::parse::ParseStream<'_>) -> syn::Result<()> {
+    match arg_name.to_string().as_ref() {
+      "numeric_type" => self.numeric_type = parse_numeric_type(input, "numeric_type"),
+      "verify" => {
+        let trials = input.parse::<LitInt>()
+          .unwrap_or_else(|_| panic!("Expected an integer after `verify = ` (the number of random trials to test)."));
+
+        self.verify = Some(trials.base10_parse()?);
+      },
+      "mode" => self.mode = Mode::parse(&input.parse::<Expr>()?)?,
+      "fma" => {
+        let enabled = input.parse::<LitBool>()
+          .unwrap_or_else(|_| panic!("Expected `true` or `false` after `fma = `."));
+
+        self.fma = enabled.value;
+      },
+      "accumulate" => self.accumulate = Some(parse_numeric_type(input, "accumulate")),
+      "inputs"     => self.inputs     = Some(parse_count(input, "inputs")?),
+      "outputs"    => self.outputs    = Some(parse_count(input, "outputs")?),
+      "max_state"  => self.max_state  = Some(parse_count(input, "max_state")?),
+      "activation" => {
+        let name = input.parse::<Ident>()
+          .unwrap_or_else(|_| panic!("Expected an activation after `activation = `. Please choose one of {{ {} }}.", ACTIVATIONS_LIST));
+
+        self.activation = Some(
+          parse_activation(&name.to_string())
+            .unwrap_or_else(|| panic!("Invalid `activation`. Please use one of {{ {} }}.", ACTIVATIONS_LIST))
+        );
+      },
+      "expect" => self.expect = Some(parse_expectations(&input.parse::<Expr>()?)?),
+      "expect_file" => {
+        let path = input.parse::<LitStr>()
+          .unwrap_or_else(|_| panic!("Expected a path after `expect_file = `."))
+          .value();
+        let data = std::fs::read_to_string(manifest_relative(&path))
+          .unwrap_or_else(|e| panic!("Failed to open `expect_file` `{}` ({}).", path, e));
+
+        self.expect = Some(parse_expectation_file(&path, &data));
+        self.expect_file = Some(path);
+      },
+      "input_scale"  => self.input_scale  = Some(parse_floats(&input.parse::<Expr>()?)?),
+      "input_offset" => self.input_offset = Some(parse_floats(&input.parse::<Expr>()?)?),
+      "head"         => self.head         = Some(Head::parse(&input.parse::<Expr>()?)?),
+      "labels"       => self.labels       = Some(parse_strings(&input.parse::<Expr>()?)?),
+      "input_names"  => self.input_names  = Some(parse_strings(&input.parse::<Expr>()?)?),
+      "output_names" => self.output_names = Some(parse_strings(&input.parse::<Expr>()?)?),
+      _ => return Err(syn::Error::new_spanned(arg_name, format!("Nonsense attribute `{}`. Expected one of {{ {} }}.", arg_name, Config::ARGUMENTS_LIST)))
+    }
+
+    // the vectors of `expect_file` are forwarded inline (the path means nothing to other crates)
+    let arg_name = match arg_name.to_string().as_ref() {
+      "expect_file" => "expect".to_string(),
+      other => other.to_string()
     };
+    if !self.specified.contains(&arg_name) { self.specified.push(arg_name); }
+
+    Ok(())
+  }
+
+  /// Re-serialize every argument that was specified (e.g. `numeric_type = f32, mode = compact`), in the order they were given.
+  /// - Parsing the result reproduces this config, minus the CGE.
+  pub fn arguments(&self) -> TokenStream {
+    // destructured, so that adding an argument without serializing it fails to compile
+    let Config { cge: _, numeric_type, verify, mode, fma, accumulate, inputs, outputs, max_state, activation, expect, expect_file: _, input_scale, input_offset, head, labels, input_names, output_names, specified } = self;
+    let count = |count: &Option<usize>| {
+      let count = proc_macro2::Literal::usize_unsuffixed(count.unwrap());
+      quote!(#count)
+    };
+
+    let arguments = specified.iter().map(|arg_name| {
+      let value = match arg_name.as_ref() {
+        "numeric_type" => numeric_type.token(),
+        "verify" => count(verify),
+        "mode" => mode.token(),
+        "fma" => quote!(#fma),
+        "accumulate" => accumulate.unwrap().token(),
+        "inputs" => count(inputs),
+        "outputs" => count(outputs),
+        "max_state" => count(max_state),
+        "activation" => {
+          let activation = Ident::new(activation_name(activation.unwrap()), proc_macro2::Span::call_site());
+          quote!(#activation)
+        },
+        "expect" => expectations_token(expect.as_ref().unwrap()),
+        "input_scale" => floats_token(input_scale.as_ref().unwrap()),
+        "input_offset" => floats_token(input_offset.as_ref().unwrap()),
+        "head" => head.as_ref().unwrap().token(),
+        "labels" => strings_token(labels.as_ref().unwrap()),
+        "input_names" => strings_token(input_names.as_ref().unwrap()),
+        "output_names" => strings_token(output_names.as_ref().unwrap()),
+        _ => unreachable!()
+      };
+
+      let arg_name = Ident::new(arg_name, proc_macro2::Span::call_site());
+      quote!(#arg_name = #value)
+    });
+
+    quote!(#(#arguments),*)
+  }
+}
+
+/// Resolve a path relative to the root of the crate being built (like `netcrate!`, and unlike the working directory of rustc,
+/// which differs between crate and workspace builds). Absolute paths are left alone.
+pub fn manifest_relative(path: &str) -> PathBuf {
+  match std::env::var("CARGO_MANIFEST_DIR") {
+    Ok(manifest_dir) => Path::new(&manifest_dir).join(path),
+    Err(_) => PathBuf::from(path)
+  }
+}
+
+/// Parse `[1.0, -0.5, ...]`
+fn parse_floats(expr: &Expr) -> syn::Result<Vec<f64>> {
+  match expr {
+    Expr::Array(array) => array.elems.iter().map(parse_float).collect(),
+    _ => Err(syn::Error::new_spanned(expr, "Expected a list of numbers, like `[1.0, -0.5]`."))
+  }
+}
+
+/// `[1.0, -0.5, ...]`, as parsed by `parse_floats`
+fn floats_token(values: &[f64]) -> TokenStream {
+  let values = values.iter().map(|&value| float_token(value));
+  quote!([#(#values),*])
+}
+
+/// Parse `["a", "b", ...]`
+fn parse_strings(expr: &Expr) -> syn::Result<Vec<String>> {
+  match expr {
+    Expr::Array(array) => array.elems
+      .iter()
+      .map(|element| match element {
+        Expr::Lit(ExprLit { lit: Lit::Str(string), .. }) => Ok(string.value()),
+        _ => Err(syn::Error::new_spanned(element, "Expected a string, like `\"idle\"`."))
+      })
+      .collect(),
+    _ => Err(syn::Error::new_spanned(expr, "Expected a list of strings, like `[\"idle\", \"walk\", \"run\"]`."))
+  }
+}
+
+/// `["a", "b", ...]`, as parsed by `parse_strings`
+fn strings_token(strings: &[String]) -> TokenStream {
+  quote!([#(#strings),*])
+}
+
+/// Parse a count (the value of the `argument = ...` being parsed)
+fn parse_count(input: syn::parse::ParseStream<'_>, argument: &str) -> syn::Result<usize> {
+  input.parse::<LitInt>()
+    .unwrap_or_else(|_| panic!("Expected an integer after `{} = `.", argument))
+    .base10_parse()
+}
+
+/// Parse a numeric type (the value of the `argument = ...` being parsed)
+pub fn parse_numeric_type(input: syn::parse::ParseStream<'_>, argument: &str) -> NumericType {
+  let name = input.parse::<TypePath>()
+    .unwrap_or_else(|_| panic!("Expected type after `{} = `. Please choose one of {{ {} }}", argument, NumericType::VARIANTS_LIST));
+  let type_ident = name.path.get_ident()
+    .unwrap_or_else(|| panic!("Invalid `{}`. Please use one of {{ {} }}.", argument, NumericType::VARIANTS_LIST));
 
-    Ok(Config { cge, numeric_type })
+  match type_ident.to_string().as_ref() {
+    "f32" => NumericType::Float32,
+    "f64" => NumericType::Float64,
+    _ => panic!("Invalid `{}`. Please use one of {{ {} }}.", argument, NumericType::VARIANTS_LIST)
   }
 }
 
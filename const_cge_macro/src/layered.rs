@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use cge::{Network, gene::{Gene, NeuronId}};
+use proc_macro2::TokenStream;
+use quote::{quote, format_ident};
+use crate::{numeric_type::NumericType, evaluator::OperationCounts};
+
+/// A layer-scheduled implementation of `evaluate`.
+pub struct Layered {
+  /// The `evaluate` function (one `const_cge::simd::layer` call per layer)
+  pub evaluate_function: TokenStream,
+
+  /// Markdown describing the layers
+  pub documentation:     String,
+
+  /// The arithmetic performed by the layered `evaluate` (including padding lanes)
+  pub operations:        OperationCounts,
+}
+
+/// Where a term of a neuron's sum comes from.
+#[derive(Clone, Copy)]
+pub enum Source {
+  Input(usize),
+  Bias(f64),
+  Recurrent(usize),
+  Neuron(NeuronId),
+}
+
+/// One weighted term of a neuron's sum.
+#[derive(Clone, Copy)]
+pub struct Term {
+  pub source: Source,
+  pub weight: f64,
+}
+
+/// Every neuron's terms (in summation order, like `evaluator::evaluate`), and the terms written to the outputs.
+pub struct Terms {
+  pub neurons: HashMap<NeuronId, Vec<Term>>,
+
+  /// Neurons in the order the genome completes them (read backwards)
+  pub order:   Vec<NeuronId>,
+  pub outputs: Vec<Term>,
+}
+
+impl Terms {
+  /// Level every neuron: a neuron's level is one more than the deepest neuron it reads (through a connection or a forward jumper).
+  /// - Neurons of a level are independent of one another, and only read neurons of earlier levels.
+  pub fn layers(neurons: &HashMap<NeuronId, Vec<Term>>, order: &[NeuronId]) -> Vec<Vec<NeuronId>> {
+    // forward jumpers may point anywhere in the genome, so this is memoized recursion
+    fn level(id: NeuronId, neurons: &HashMap<NeuronId, Vec<Term>>, levels: &mut HashMap<NeuronId, usize>) -> usize {
+      if let Some(&level) = levels.get(&id) { return level; }
+
+      let level = 1 + neurons[&id]
+        .iter()
+        .map(|term| match term.source {
+          Source::Neuron(source) => level(source, neurons, levels),
+          _ => 0
+        })
+        .max()
+        .unwrap_or(0);
+
+      levels.insert(id, level);
+      level
+    }
+
+    let mut levels = HashMap::new();
+    let mut layers: Vec<Vec<NeuronId>> = vec![];
+    for &id in order {
+      let level = level(id, neurons, &mut levels);
+      if layers.len() < level { layers.resize(level, vec![]); }
+      layers[level - 1].push(id);
+    }
+
+    layers
+  }
+}
+
+/// Rebuild each neuron's list of terms from the genome.
+pub fn terms(network: &Network<f64>, recurrence_table: &HashMap<NeuronId, usize>) -> Terms {
+  let genome = network.genome();
+  let mut neurons: HashMap<NeuronId, Vec<Term>> = HashMap::new();
+  let mut order = vec![];
+  let mut stack: Vec<Term> = vec![];
+
+  for (gene_index, gene) in genome.iter().enumerate().rev() {
+    let term = match gene {
+      Gene::Input(input)       => Term { source: Source::Input(input.id().as_usize()), weight: input.weight() },
+      Gene::Bias(b)            => Term { source: Source::Bias(b.value()), weight: 1.0 },
+      Gene::ForwardJumper(f)   => Term { source: Source::Neuron(f.source_id()), weight: f.weight() },
+      Gene::RecurrentJumper(r) => {
+        let state = recurrence_table
+          .get(&r.source_id())
+          .unwrap_or_else(|| panic!("Corrupt CGE: encountered a recurrent connection (gene {}) with an invalid neuron ID ({})", gene_index, r.source_id().as_usize()));
+
+        Term { source: Source::Recurrent(*state), weight: r.weight() }
+      },
+      Gene::Neuron(n) => {
+        let input_count = n.num_inputs();
+        if input_count == 0 || input_count > stack.len() {
+          panic!("Corrupt CGE: neuron (ID {:?}) did not receive enough inputs (expected {}, but only received {})", n.id(), input_count, stack.len());
+        }
+
+        // the oldest term is summed first (like `cge`)
+        let terms = stack.split_off(stack.len() - input_count);
+        neurons.insert(n.id(), terms);
+        order.push(n.id());
+
+        Term { source: Source::Neuron(n.id()), weight: n.weight() }
+      }
+    };
+
+    stack.push(term);
+  }
+
+  for terms in neurons.values() {
+    for term in terms {
+      if let Source::Neuron(id) = term.source {
+        if !neurons.contains_key(&id) { panic!("Corrupt CGE: encountered a forward jumper with an invalid neuron ID ({})", id.as_usize()); }
+      }
+    }
+  }
+
+  Terms { neurons, order, outputs: stack }
+}
+
+/// Level every neuron of the genome, and emit one vectorized computation per level.
+/// - A neuron's level is one more than the deepest neuron it reads (through a connection or a forward jumper),
+///   so all neurons of a level are independent of one another.
+/// - Terms are summed in the same order as `evaluator::evaluate`, so results are bitwise identical to `mode = arithmetic`.
+pub fn synthesize_layered(
+  network:          &Network<f64>,
+  recurrence_table: &HashMap<NeuronId, usize>,
+  numeric_type:     NumericType,
+  activation:       TokenStream,
+) -> Layered {
+  let Terms { neurons, order, outputs } = terms(network, recurrence_table);
+
+  let layers = Terms::layers(&neurons, &order);
+
+  let name = |id: NeuronId| format_ident!("n{}", id.as_usize());
+  let source = |source: Source| match source {
+    Source::Input(index)     => quote!(inputs[#index]),
+    Source::Bias(value)      => numeric_type.naive_conversion(value),
+    Source::Recurrent(state) => quote!(self.persistence[#state]),
+    Source::Neuron(id)       => { let name = name(id); quote!(#name) }
+  };
+
+  let mut operations = OperationCounts::default();
+  let mut computations = vec![];
+
+  for layer in &layers {
+    let width = layer.len();
+    let fan_in = layer.iter().map(|id| neurons[id].len()).max().unwrap();
+
+    // row `j` holds the `j`th term of every neuron in the layer (padded with `0.0 * -0.0`)
+    let (mut source_rows, mut weight_rows) = (vec![], vec![]);
+    for j in 0..fan_in {
+      let (sources, weights): (Vec<_>, Vec<_>) = layer
+        .iter()
+        .map(|id| match neurons[id].get(j) {
+          Some(term) => (source(term.source), numeric_type.naive_conversion(term.weight)),
+          None       => (numeric_type.naive_conversion(0.0), numeric_type.naive_conversion(-0.0))
+        })
+        .unzip();
+
+      source_rows.push(quote!([#(#sources),*]));
+      weight_rows.push(quote!([#(#weights),*]));
+    }
+
+    let names = layer.iter().map(|&id| name(id));
+    computations.push(quote! {
+      let [#(#names),*] = const_cge::simd::layer(
+        &[#(#source_rows),*],
+        &[#(#weight_rows),*],
+        #activation
+      );
+    });
+
+    operations.muls += width * fan_in;
+    operations.adds += width * (fan_in - 1);
+    operations.activations += width;
+  }
+
+  // outputs are weighted by their own neuron's weight
+  let output_count = outputs.len();
+  for (index, term) in outputs.iter().enumerate() {
+    let (source, weight) = (source(term.source), numeric_type.naive_conversion(term.weight));
+    computations.push(quote! { outputs[#index] = #source * #weight; });
+    operations.muls += 1;
+  }
+
+  // recurrent state is updated at the very end
+  for (&id, &state) in recurrence_table {
+    let name = name(id);
+    computations.push(quote! { self.persistence[#state] = #name; });
+  }
+
+  let documentation = format!(
+"Layer scheduled (SIMD)
+  - {layer_count} layers of independent neurons (widest: {widest}), each evaluated with `const_cge::simd::layer`
+  - neurons with fewer inputs than the rest of their layer are padded (counted in `MUL_COUNT` and `ADD_COUNT`)",
+    layer_count = layers.len(),
+    widest = layers.iter().map(Vec::len).max().unwrap_or(0),
+  );
+
+  let input_count = network.num_inputs();
+  let numeric_token = numeric_type.token();
+  let self_argument = if recurrence_table.is_empty() { quote!() } else { quote!(&mut self,) };
+
+  let evaluate_function = quote! {
+    /// Evaluate the network for a single input vector, one layer of independent neurons at a time.
+    ///
+    /// Properties:
+    /// - allocationless, heapless, no_std compatible
+    /// - every layer is a handful of elementwise multiply-adds over arrays (one lane per neuron), which vectorize reliably
+    /// - bitwise identical to `mode = arithmetic`
+    pub fn evaluate(#self_argument inputs: &[#numeric_token; #input_count], outputs: &mut [#numeric_token; #output_count]) {
+      #(#computations)*
+    }
+  };
+
+  Layered { evaluate_function, documentation, operations }
+}
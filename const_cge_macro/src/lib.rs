@@ -6,9 +6,23 @@ use proc_macro::TokenStream;
 mod stack;
 mod numeric_type;
 mod evaluator;
+mod mode;
+mod lut;
+mod compact;
+mod layered;
 mod recurrency; 
 use recurrency::RecurrencyConstraint;
+mod interface;
+mod expectation;
+mod normalization;
+mod head;
+mod named;
+mod pipeline;
+mod ensemble;
 mod synthesis;
+mod verification;
+mod bank;
+mod fingerprint;
 mod macro_core;
 mod netcrate_invocation; 
 use netcrate_invocation::NetcrateInvocation;
@@ -17,7 +31,26 @@ use netcrate_invocation::NetcrateInvocation;
 /// Adds the required fields and functions for executing a network loaded from a CGE file.
 /// - If your network has recurrent     architecture, it only works on unit structs (no fields).
 /// - If your network has non-recurrent architecture, it works on any struct or enum.
+/// - The CGE path is relative to the crate root (the directory containing `Cargo.toml`), and cargo rebuilds when the file changes.
+/// - To be explicit about the source, use `file = "net.cge"`, `data = "<CGE data>"`, or `env = "MODEL_PATH"` (a path read from the environment at build time).
 /// - To control target numeric type (`f32`/`f64`), use the `numeric_type` attribute: `#[network("net.cge", numeric_type = f32)`.
+/// - To generate a test checking the compiled network against the `cge` runtime, use the `verify` attribute: `#[network("net.cge", verify = 1000)]`.
+///   (runs 1000 random trials, requires `cge` as a dev-dependency, and the struct must be declared at module level)
+/// - To compile a tiny (1 or 2 input) nonrecurrent network into an interpolated lookup table, use `mode`: `#[network("net.cge", mode = lut(resolution = 256, ranges = [-1.0..1.0]))]`.
+/// - To trade speed for code size (bytecode run by a shared interpreter), use `mode = compact`.
+/// - To evaluate layers of independent neurons in vector lanes, use `mode = simd` (requires the `simd` feature).
+/// - To control the precision of neuron sums, use `fma = true` and/or `accumulate = f64`.
+/// - To assert the network's interface (failing loudly when it changes), use `inputs = N`, `outputs = N`, `max_state = N` and/or `activation = tanh`.
+/// - To check golden vectors (at compile time, and with a generated test of the compiled network), use `expect = [([1.0, 0.5], [0.73]), ...]`
+///   or `expect_file = "vectors.json"`. Recurrent networks see the vectors as consecutive steps from their initial state.
+/// - To fold input normalization into the network (free at runtime), use `input_scale = [...]` and/or `input_offset = [...]`
+///   (each input becomes `input * scale + offset`), or put `input_scale` / `input_offset` in the `extra` section of the CGE file.
+/// - To post-process the outputs, use `head = softmax`, `head = argmax` (one-hot) or `head = clamp(lo, hi)` (`evaluate_raw` skips the head).
+/// - To name the outputs of a classifier, use `labels = ["idle", "walk", "run"]`, generating a `<Name>Label` enum and `classify`.
+/// - To name the inputs and outputs, use `input_names = [...]` and/or `output_names = [...]` (or put them in the `extra` section of the CGE file),
+///   generating `#[repr(C)]` `<Name>Inputs` / `<Name>Outputs` structs and `evaluate_named`.
+/// - Recurrent networks also get a `<Name>Bank<N>` type, evaluating `N` instances (each with their own state) with `evaluate_all`,
+///   and a `<Name>State` type for snapshots of their recurrent state (`snapshot` / `restore`).
 /// ```rust
 /// use const_cge::network;
 /// 
@@ -48,6 +81,24 @@ pub fn nonrecurrent(attr: TokenStream, item: TokenStream) -> proc_macro::TokenSt
   macro_core::core(parse_invocation!(attr, item, RecurrencyConstraint::Forbidden))
 }
 
+/// Compiles several CGE files (sharing their input and output dimensions) into one network, combining their outputs.
+/// - `combine = mean` (the default), `combine = median`, or `combine = vote` (each member votes for its largest output,
+///   and outputs become the share of votes).
+/// - Members share the inputs, and their recurrent state is laid out back to back in one persistence array.
+/// - `evaluate_members` returns the outputs of every member, before they are combined.
+/// - Like other networks, ensembles get `FINGERPRINT`, `snapshot`/`restore` and a bank (when recurrent), and `verify = 1000`.
+/// ```rust
+/// #[ensemble("walk_a.cge", "walk_b.cge", "walk_c.cge", combine = median, numeric_type = f32)]
+/// struct Walker;
+/// ```
+#[proc_macro_attribute]
+pub fn ensemble(attr: TokenStream, item: TokenStream) -> proc_macro::TokenStream {
+  let invocation = parse_macro_input!(attr as ensemble::EnsembleInvocation);
+  let item = parse_macro_input!(item as syn::Item);
+
+  ensemble::ensemble(invocation, item).into()
+}
+
 /// API for `netcrate` authors.
 /// 
 /// ## Usage
@@ -72,49 +123,121 @@ pub fn nonrecurrent(attr: TokenStream, item: TokenStream) -> proc_macro::TokenSt
 /// `src/my_net.cge`).
 /// 
 /// ## Multiple Networks
-/// Unfortunately, you cannot use the module system to organize networks for users,
-/// they will all be forcibly hoisted to the top level.
+/// Exported networks are hoisted to the top level of your crate, regardless of the module `netcrate!` is invoked in.
+/// To organize them, give them a namespace:
 /// 
 /// ```rust
-/// const_cge::netcrate!(ocr          = "ocr.cge");
-/// const_cge::netcrate!(denoise      = "denoise.cge");
-/// const_cge::netcrate!(cart_pole    = "cart.cge");   
-/// const_cge::netcrate!(octopus_legs = "octopus.cge");
+/// const_cge::netcrate!(ocr                 = "ocr.cge");
+/// const_cge::netcrate!(vision::ocr         = "vision/ocr.cge");
+/// const_cge::netcrate!(vision::denoise     = "vision/denoise.cge");
+/// const_cge::netcrate!(control::cart_pole  = "control/cart.cge");
 /// ```
 /// 
 /// Now, end users can use your network like:
 /// ```rust
-/// #[network(network_zoo::ocr, numeric_type = f32)]
+/// #[network(network_zoo::vision::ocr, numeric_type = f32)]
 /// struct HandwritingOCR;
 /// ```
+/// 
+/// ## Metadata
+/// Declare metadata along with the network, users can query it with `const_cge::metadata!` (without compiling the network):
+/// ```rust
+/// const_cge::netcrate!(
+///   vision::ocr  = "vision/ocr.cge",
+///   version      = "1.2.0",
+///   license      = "MIT",
+///   description  = "Handwritten digit recognition (8x8 grayscale)",
+///   output_names = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"]
+/// );
+/// ```
+/// - `input_names` / `output_names` label every input / output (and must match the network), they are also defaults (see below).
+/// - `description` defaults to the description in the CGE file.
+/// 
+/// ## Defaults
+/// Any argument of `#[network]` can be given a default, users can still override it:
+/// ```rust
+/// const_cge::netcrate!(ocr = "ocr.cge", numeric_type = f32, mode = compact);
+/// ```
 #[proc_macro]
 pub fn netcrate(input: TokenStream) -> proc_macro::TokenStream {
-  let NetcrateInvocation { name, path } = parse_macro_input!(input as NetcrateInvocation);
+  let invocation = parse_macro_input!(input as NetcrateInvocation);
 
   // convert to absolute path based on the currently-building crate
-  let manifest_path = std::env::var("CARGO_MANIFEST_DIR").expect("Failed to discover crate manifest directory!");
-  let cge_path = std::path::Path::new(&manifest_path).join(path);
+  let cge_path = invocation_parser::manifest_relative(&invocation.path);
+  let rebuild_anchor = macro_core::rebuild_anchor(&cge_path);
 
   // extract the CGE data
   let cge_data = {
-    let mut file = std::fs::File::open(cge_path).expect("Failed to open CGE file");
+    let mut file = std::fs::File::open(&cge_path).expect("Failed to open CGE file");
     let mut contents = String::new();
     file.read_to_string(&mut contents).expect("Failed to read CGE file");
     contents
   };
 
+  let metadata = netcrate_invocation::metadata(&invocation, &cge_data);
+  let exported = netcrate_invocation::exported_macro(&invocation.namespace, &invocation.name);
+
+  // the author's defaults come first, so the user's arguments override them
+  let defaults = match invocation.defaults.specified.is_empty() {
+    true  => quote::quote!(),
+    false => { let defaults = invocation.defaults.arguments(); quote::quote!(#defaults,) }
+  };
+
+  // namespaced networks are reached through their namespace, so hide the mangled name
+  let hidden = if invocation.namespace.is_empty() { quote::quote!() } else { quote::quote!(#[doc(hidden)]) };
+
   quote::quote! {
+    #rebuild_anchor
+
     #[allow(unused_macros)] // silence warning author will see about some invisible macro
     #[macro_export]         // force this macro to be hoisted and made public
-    macro_rules! #name {    // name the macro according to user
+    #hidden
+    macro_rules! #exported {  // name the macro according to user
+      (metadata) => { #metadata };
       (
         $invocation: ident,
         $item: item,
         $($rest:stmt),*
       ) => {
-        #[const_cge::$invocation(#cge_data, $($rest),*)]
+        #[const_cge::$invocation(data = #cge_data, #defaults $($rest),*)]
         $item
       }
     }
   }.into()
-}
\ No newline at end of file
+}
+
+/// Chain networks into one, with every connection checked at compile time.
+/// - Generates a struct owning every stage (and its recurrent state), whose `evaluate` runs the stages in order,
+///   passing outputs through stack buffers sized by `OUTPUT_COUNT` / `INPUT_COUNT`.
+/// - A parenthesized group of networks splits the previous stage's outputs between them (in order), and concatenates their outputs.
+/// - Pipelines are stages too (they implement `const_cge::Stage`), so they nest.
+/// 
+/// ```rust
+/// #[network("perception.cge", numeric_type = f32)] struct Perception;
+/// #[network("planner.cge",    numeric_type = f32)] struct Planner;
+/// #[network("reflex.cge",     numeric_type = f32)] struct Reflex;
+/// #[network("motor.cge",      numeric_type = f32)] struct Motor;
+/// 
+/// const_cge::pipeline!(pub Robot = Perception => (Planner, Reflex) => Motor);
+/// 
+/// let mut robot = Robot::default();
+/// robot.evaluate(&sensors, &mut actuators);
+/// ```
+#[proc_macro]
+pub fn pipeline(input: TokenStream) -> proc_macro::TokenStream {
+  pipeline::pipeline(parse_macro_input!(input as pipeline::PipelineInvocation)).into()
+}
+
+/// The metadata a netcrate author declared for one of their networks (a `const_cge::Metadata`, usable in `const`).
+/// 
+/// ```rust
+/// const OCR: const_cge::Metadata = const_cge::metadata!(network_zoo::vision::ocr);
+/// const _: () = assert!(OCR.output_count == 10);
+/// ```
+#[proc_macro]
+pub fn metadata(input: TokenStream) -> proc_macro::TokenStream {
+  let path = parse_macro_input!(input as syn::Path);
+  let exported = netcrate_invocation::resolve(&path);
+
+  quote::quote!(#exported!(metadata)).into()
+}
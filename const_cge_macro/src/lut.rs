@@ -0,0 +1,187 @@
+use cge::Network;
+use proc_macro2::TokenStream;
+use quote::quote;
+use crate::{numeric_type::NumericType, evaluator::OperationCounts};
+
+/// A lookup table implementation of `evaluate`.
+pub struct Lut {
+  /// The `evaluate` function (interpolating between samples of a `static` table)
+  pub evaluate_function: TokenStream,
+
+  /// Markdown describing the table and its measured accuracy
+  pub documentation:     String,
+
+  /// The arithmetic performed by the interpolating `evaluate`
+  pub operations:        OperationCounts,
+
+  /// The largest error measured against the exact network (inside `ranges`, including `numeric_type` conversion)
+  pub max_error:         f64,
+}
+
+/// Sample the network over a grid (using the `cge` runtime as the exact evaluator),
+/// and generate an `evaluate` which linearly interpolates between samples.
+/// - Inputs outside of `ranges` are clamped to them.
+pub fn synthesize_lut(
+  network:      &Network<f64>,
+  output_count: usize,
+  numeric_type: NumericType,
+  resolution:   usize,
+  ranges:       &[(f64, f64)]
+) -> Lut {
+  let input_count = network.num_inputs();
+  if !(1..=2).contains(&input_count) {
+    panic!("`mode = lut(...)` only supports networks with 1 or 2 inputs (this network has {}).", input_count);
+  }
+  if ranges.len() != input_count {
+    panic!("`mode = lut(...)` needs one range per input (this network has {} inputs, but {} ranges were given).", input_count, ranges.len());
+  }
+
+  let mut network = network.clone();
+  let mut exact = |inputs: &[f64]| -> Vec<f64> {
+    network.clear_state();
+    network.evaluate(inputs).expect("Failed to evaluate network").to_vec()
+  };
+
+  // grid coordinate -> input value
+  let at = |axis: usize, position: f64| {
+    let (low, high) = ranges[axis];
+    low + (high - low) * position / (resolution - 1) as f64
+  };
+
+  // sample the grid (row-major, last input varies fastest)
+  let samples = if input_count == 1 {
+    (0..resolution).map(|i| exact(&[at(0, i as f64)])).collect::<Vec<_>>()
+  } else {
+    (0..resolution)
+      .flat_map(|i| (0..resolution).map(move |j| (i, j)))
+      .map(|(i, j)| exact(&[at(0, i as f64), at(1, j as f64)]))
+      .collect::<Vec<_>>()
+  };
+
+  // simulate the generated `evaluate` (table, clamping and interpolation all in `numeric_type`),
+  // so the measured error includes the conversion, not just the interpolation
+  let last = resolution - 1;
+  let round = |v: f64| match numeric_type { NumericType::Float64 => v, NumericType::Float32 => v as f32 as f64 };
+  let grid = |axis: usize, input: f64| {
+    let (low, high) = ranges[axis];
+    let x = round(round(input - round(low)) * round(last as f64 / (high - low)));
+    let x = if x > 0.0 { if x < last as f64 { x } else { last as f64 } } else { 0.0 };
+    let i = (x as usize).min(last - 1);
+    (i, round(x - i as f64))
+  };
+  let lerp = |a: f64, b: f64, t: f64| round(a + round(round(b - a) * t));
+  let table = |index: usize, o: usize| round(samples[index][o]);
+  let interpolate = |inputs: &[f64], o: usize| if input_count == 1 {
+    let (i, t) = grid(0, inputs[0]);
+    lerp(table(i, o), table(i + 1, o), t)
+  } else {
+    let ((i, tx), (j, ty)) = (grid(0, inputs[0]), grid(1, inputs[1]));
+    let top = lerp(table(i * resolution + j, o), table(i * resolution + j + 1, o), ty);
+    let bottom = lerp(table((i + 1) * resolution + j, o), table((i + 1) * resolution + j + 1, o), ty);
+    lerp(top, bottom, tx)
+  };
+
+  // measure against the exact network at a few points inside every cell (away from the samples, where the error vanishes)
+  let mut max_error = 0f64;
+  let mut measure = |inputs: &[f64]| {
+    let inputs = inputs.iter().map(|&x| round(x)).collect::<Vec<_>>();
+    for (o, expected) in exact(&inputs).iter().enumerate() {
+      max_error = max_error.max((interpolate(&inputs, o) - expected).abs());
+    }
+  };
+  if input_count == 1 {
+    for i in 0..last {
+      for offset in [0.25, 0.5, 0.75] { measure(&[at(0, i as f64 + offset)]); }
+    }
+  } else {
+    for i in 0..last {
+      for j in 0..last {
+        for (x, y) in [(0.5, 0.5), (0.25, 0.25), (0.25, 0.75), (0.75, 0.25), (0.75, 0.75)] {
+          measure(&[at(0, i as f64 + x), at(1, j as f64 + y)]);
+        }
+      }
+    }
+  }
+
+  let numeric_token = numeric_type.token();
+  let entry = |sample: &Vec<f64>| {
+    let values = sample.iter().map(|&v| numeric_type.naive_conversion(v));
+    quote!([#(#values),*])
+  };
+
+  // position of input `axis` on the grid, clamped to it (NaN ends up at the low end)
+  let position = |axis: usize| {
+    let (low, high) = ranges[axis];
+    let scale = numeric_type.naive_conversion(last as f64 / (high - low));
+    let low = numeric_type.naive_conversion(low);
+    let end = numeric_type.naive_conversion(last as f64);
+    quote! {{
+      let x = (inputs[#axis] - #low) * #scale;
+      let x = if x > 0.0 { if x < #end { x } else { #end } } else { 0.0 };
+      let i = x as usize; // floor (x is never negative)
+      let i = if i < #last { i } else { #last - 1 }; // the top edge interpolates within the last cell
+      (i, x - i as #numeric_token)
+    }}
+  };
+
+  let (table, interpolation, operations) = if input_count == 1 {
+    let rows = samples.iter().map(entry);
+    let x = position(0);
+
+    (
+      quote!(static TABLE: [[#numeric_token; #output_count]; #resolution] = [#(#rows),*];),
+      quote! {
+        let (i, t) = #x;
+        let (a, b) = (&TABLE[i], &TABLE[i + 1]);
+        for o in 0..#output_count {
+          outputs[o] = a[o] + (b[o] - a[o]) * t;
+        }
+      },
+      OperationCounts { muls: 1 + output_count, adds: 1 + 2 * output_count, activations: 0 }
+    )
+  } else {
+    let rows = samples.chunks(resolution).map(|row| {
+      let row = row.iter().map(entry);
+      quote!([#(#row),*])
+    });
+    let (x, y) = (position(0), position(1));
+
+    (
+      quote!(static TABLE: [[[#numeric_token; #output_count]; #resolution]; #resolution] = [#(#rows),*];),
+      quote! {
+        let (i, tx) = #x;
+        let (j, ty) = #y;
+        for o in 0..#output_count {
+          let top    = TABLE[i][j][o]     + (TABLE[i][j + 1][o]     - TABLE[i][j][o])     * ty;
+          let bottom = TABLE[i + 1][j][o] + (TABLE[i + 1][j + 1][o] - TABLE[i + 1][j][o]) * ty;
+          outputs[o] = top + (bottom - top) * tx;
+        }
+      },
+      OperationCounts { muls: 2 + 3 * output_count, adds: 2 + 6 * output_count, activations: 0 }
+    )
+  };
+
+  let documentation = format!(
+"Compiled to a lookup table
+  - {points} samples (`{resolution}` per input), inputs clamped to {ranges}
+  - `Self::evaluate` interpolates linearly between samples
+  - max error: `{max_error:.3e}` (measured against the exact network at several points inside every cell, in `{numeric_token}`)",
+    points = samples.len(),
+    ranges = ranges.iter().map(|(low, high)| format!("`{:?}..{:?}`", low, high)).collect::<Vec<_>>().join(", "),
+  );
+
+  let evaluate_function = quote! {
+    /// Evaluate the network for a single input vector, by interpolating in a lookup table.
+    ///
+    /// Properties:
+    /// - allocationless, heapless, no_std compatible
+    /// - a handful of memory reads and arithmetic, regardless of network size
+    /// - inputs outside of the declared ranges are clamped to them
+    pub fn evaluate(inputs: &[#numeric_token; #input_count], outputs: &mut [#numeric_token; #output_count]) {
+      #table
+      #interpolation
+    }
+  };
+
+  Lut { evaluate_function, documentation, operations, max_error }
+}
@@ -1,9 +1,10 @@
 extern crate proc_macro; 
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{quote, format_ident};
 use syn::{Item, parse_quote, Fields};
-use crate::{numeric_type::NumericType, recurrency::RecurrencyConstraint};
-use super::synthesis::{synthesize, Synthesis};
+use cge::Activation;
+use crate::{numeric_type::NumericType, recurrency::RecurrencyConstraint, mode::Mode, expectation::{Expectation, check_expectations, expectation_module}, head::{Head, label_enum}, named::named_io, pipeline::stage_impl};
+use super::{synthesis::{synthesize, Synthesis}, verification::{verification_module, Reference}, bank::bank, netcrate_invocation::resolve, invocation_parser::manifest_relative};
 
 /// All the invocation information.
 pub struct Invocation {
@@ -25,7 +26,14 @@ pub enum CgeType {
   File(String),
 
   /// Given direct data as a string.
-  Direct(String)
+  Direct(String),
+
+  /// Given an environment variable holding a path to a CGE file (read at build time).
+  Environment { variable: String, path: String }
+}
+
+impl CgeType {
+  pub const KINDS_LIST: &'static str = "file = \"path\", data = \"cge data\", env = \"VARIABLE\"";
 }
 
 /// Details about the invocation config of the macro.
@@ -34,57 +42,108 @@ pub struct Config {
   pub cge:          CgeType,
 
   /// The **target** numeric type.
-  pub numeric_type: NumericType
+  pub numeric_type: NumericType,
+
+  /// Number of random trials for a generated test against the `cge` runtime (no test if `None`).
+  pub verify:       Option<usize>,
+
+  /// How the network is compiled.
+  pub mode:         Mode,
+
+  /// Emit fused multiply-adds for each neuron's sum.
+  pub fma:          bool,
+
+  /// The type neuron sums are accumulated in (the numeric type if `None`).
+  pub accumulate:   Option<NumericType>,
+
+  /// Asserted number of inputs.
+  pub inputs:       Option<usize>,
+
+  /// Asserted number of outputs.
+  pub outputs:      Option<usize>,
+
+  /// Asserted upper bound on the number of recurrent state elements.
+  pub max_state:    Option<usize>,
+
+  /// Asserted activation function.
+  pub activation:   Option<Activation>,
+
+  /// Golden vectors (`(inputs, outputs)`), checked at compile time and by a generated test.
+  pub expect:       Option<Vec<Expectation>>,
+
+  /// The file `expect` was read from (its vectors are inlined into `expect`).
+  pub expect_file:  Option<String>,
+
+  /// Per-input scale of the normalization folded into the network (`input * scale + offset`).
+  pub input_scale:  Option<Vec<f64>>,
+
+  /// Per-input offset of the normalization folded into the network (`input * scale + offset`).
+  pub input_offset: Option<Vec<f64>>,
+
+  /// Post-processing applied to the outputs of `evaluate`.
+  pub head:         Option<Head>,
+
+  /// Labels of the outputs (generating a label enum, and `classify`).
+  pub labels:       Option<Vec<String>>,
+
+  /// Names of the inputs (generating a named `Inputs` struct, and `evaluate_named`).
+  pub input_names:  Option<Vec<String>>,
+
+  /// Names of the outputs (generating a named `Outputs` struct, and `evaluate_named`).
+  pub output_names: Option<Vec<String>>,
+
+  /// The arguments that were given explicitly (in order), everything else is a default.
+  pub specified:    Vec<String>,
+}
+
+impl Config {
+  pub const ARGUMENTS_LIST: &'static str = "numeric_type, verify, mode, fma, accumulate, inputs, outputs, max_state, activation, expect, expect_file, input_scale, input_offset, head, labels, input_names, output_names";
 }
 
 pub fn core(invocation: Invocation) -> TokenStream {
-  if let CgeType::Module(p) = invocation.config.cge {
+  if let CgeType::Module(ref p) = invocation.config.cge {
     let invocation_ident = match invocation.recurrency_constraint {
       RecurrencyConstraint::DontCare  => quote!(network),
       RecurrencyConstraint::Required  => quote!(recurrent),
       RecurrencyConstraint::Forbidden => quote!(nonrecurrent),
     };
 
-    let numeric_token = invocation.config.numeric_type.token();
-
+    let arguments = invocation.config.arguments();
+    let expect_file_anchor = invocation.config.expect_file.as_ref().map(|path| rebuild_anchor(&manifest_relative(path)));
+    let exported = resolve(p);
     let item = invocation.item;
     return quote! {
       // we have been given another macro (the one prepared by `netcrate!`),
       // which then expands to the `#[network("literal_cge_data")]` etc.,
       // which then expands to the actual implementation. convoluted.
-      #p!(
+      // only explicit arguments are forwarded, so they override the netcrate author's defaults.
+      #exported!(
         #invocation_ident,
         #item,
-        
-        // ADD MORE ARGUMENTS HERE IF YOU ADD SUPPORT FOR THEM IN THE MAIN MACRO (network, etc)
-        numeric_type = #numeric_token
+        #arguments
       );
+
+      // (`expect_file` is forwarded inline)
+      #expect_file_anchor
     }.into()
   }
 
   let Synthesis {
     recurrency_count,
+    source,
+    fingerprint,
     documentation,
     persistence_field,
     associated_constants,
     persistence_methods,
-    evaluate_function
+    evaluate_function,
+    wrapper_functions,
+    approximation,
+    lanes
   } = synthesize(&invocation);
 
-  // if the recurrency of the network does not conform to our constraint, panic.
-  match invocation.recurrency_constraint {
-    // no constraint
-    RecurrencyConstraint::DontCare  => {},
-
-    // require recurrency
-    RecurrencyConstraint::Required  => if recurrency_count == 0 { panic!("Network is not recurrent (it was demanded)."); },
-
-    // forbid recurrency
-    RecurrencyConstraint::Forbidden => if recurrency_count != 0 { panic!("Network is recurrent (it was forbidden)."); }
-  }
-
   // fail for enum and non-unit structs (ONLY IF the network requires a persistence field).
-  let name = match invocation.item {
+  let (name, visibility) = match invocation.item {
     syn::Item::Struct(ref s) => {
       if recurrency_count != 0 {
         match &s.fields {
@@ -94,7 +153,7 @@ pub fn core(invocation: Invocation) -> TokenStream {
         }
       }
 
-      s.ident.clone()
+      (s.ident.clone(), s.vis.clone())
     },
     syn::Item::Enum(ref e) => {
       if recurrency_count != 0 {
@@ -107,11 +166,38 @@ pub fn core(invocation: Invocation) -> TokenStream {
         panic!("Your network is recurrent. Enums cannot always store recurrent state, so are not supported as targets of recurrent networks (for now).");
       }
 
-      e.ident.clone()
+      (e.ident.clone(), e.vis.clone())
     },
     _ => panic!("Unsupported language construct (`struct` and `enum` only).")
   };
 
+  // recurrent networks get a companion type, evaluating many instances (each with their own state)
+  let bank = match invocation.item {
+    Item::Struct(_) if recurrency_count != 0 => Some(bank(&name, &visibility, invocation.config.numeric_type, recurrency_count, fingerprint, &lanes, invocation.config.head.as_ref())),
+    _ => None
+  };
+
+  // optionally, an enum of output labels (and `classify`, returning one)
+  let (labels, classify_function) = match invocation.config.labels {
+    Some(ref labels) => {
+      let (labels, classify_function) = label_enum(&name, &visibility, labels, recurrency_count, invocation.config.numeric_type);
+      (Some(labels), Some(classify_function))
+    },
+    None => (None, None)
+  };
+
+  // optionally, named inputs and outputs (from the attribute, or the CGE file)
+  let (named_structs, evaluate_named) = match named_io(&name, &visibility, &source, invocation.config.input_names.as_ref(), invocation.config.output_names.as_ref(), recurrency_count, invocation.config.numeric_type) {
+    Some((named_structs, evaluate_named)) => (Some(named_structs), Some(evaluate_named)),
+    None => (None, None)
+  };
+
+  // every network can be a stage of a `pipeline!`
+  let stage = stage_impl(&name, invocation.config.numeric_type, recurrency_count);
+
+  // generated tests check the network itself, before any output head
+  let evaluate = if invocation.config.head.is_some() { format_ident!("evaluate_raw") } else { format_ident!("evaluate") };
+
   let item = if let Item::Struct(mut s) = invocation.item.clone() {
     // we now need to add the recurrent data field
     s.fields = Fields::Named(parse_quote!({ #persistence_field }));
@@ -121,6 +207,30 @@ pub fn core(invocation: Invocation) -> TokenStream {
     invocation.item
   };
 
+  // optionally, golden vectors (checked now against the `cge` runtime, and later against the compiled network)
+  let expectation = invocation.config.expect.as_ref().map(|expectations| {
+    check_expectations(&source, expectations, invocation.config.numeric_type);
+    expectation_module(&name, &evaluate, expectations, recurrency_count, invocation.config.numeric_type, approximation.as_ref())
+  });
+
+  // cargo only rebuilds when a tracked file changes, so track the CGE file (and the golden vectors)
+  let expect_file_anchor = invocation.config.expect_file.as_ref().map(|path| rebuild_anchor(&manifest_relative(path)));
+  let rebuild_anchor = match invocation.config.cge {
+    CgeType::File(ref path) => Some(rebuild_anchor(&manifest_relative(path))),
+
+    // (and the variable, so changing it picks a new file)
+    CgeType::Environment { ref variable, ref path } => {
+      let file = rebuild_anchor(&manifest_relative(path));
+      Some(quote! { #file const _: &str = env!(#variable); })
+    },
+    _ => None
+  };
+
+  // optionally, a test against the `cge` runtime
+  let verification = invocation.config.verify.map(|trials| {
+    verification_module(&name, &evaluate, &Reference::network(&source), recurrency_count, invocation.config.numeric_type, trials, approximation.as_ref())
+  });
+
   quote! {
     #documentation
     #[derive(Clone, Copy, Default)]
@@ -130,6 +240,24 @@ pub fn core(invocation: Invocation) -> TokenStream {
       #associated_constants
       #persistence_methods
       #evaluate_function
+      #wrapper_functions
+      #classify_function
+      #evaluate_named
     }
+
+    #stage
+    #labels
+    #named_structs
+    #bank
+    #verification
+    #expectation
+    #rebuild_anchor
+    #expect_file_anchor
   }.into()
+}
+
+/// An item including `path` (but never used), so cargo rebuilds the invoking crate when the file changes.
+pub fn rebuild_anchor(path: &std::path::Path) -> proc_macro2::TokenStream {
+  let path = path.to_str().expect("CGE file paths must be valid unicode");
+  quote! { const _: &[u8] = include_bytes!(#path); }
 }
\ No newline at end of file
@@ -0,0 +1,123 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Expr, ExprLit, ExprUnary, ExprRange, ExprAssign, ExprCall, Lit, UnOp, RangeLimits};
+
+/// How the network is compiled
+#[derive(Clone)]
+pub enum Mode {
+  /// Straight-line arithmetic, one expression per gene (the default).
+  Arithmetic,
+
+  /// Emit the genome as `static` bytecode, run by the interpreter in `const_cge::compact`.
+  /// - code size grows with data (shared interpreter), not instructions, at the cost of speed
+  Compact,
+
+  /// Level the genome into layers of independent neurons, and evaluate each layer with `const_cge::simd::layer` (one lane per neuron).
+  /// - requires the `simd` feature
+  Simd,
+
+  /// Sample the network over a grid at compile time, and interpolate linearly between samples.
+  /// - only for nonrecurrent networks with 1 or 2 inputs
+  Lut {
+    /// Samples along each input axis (including both ends of the range)
+    resolution: usize,
+
+    /// The range each input is expected to fall in (inputs are clamped to it)
+    ranges:     Vec<(f64, f64)>
+  }
+}
+
+impl Mode {
+  pub const VARIANTS_LIST: &'static str = "arithmetic, compact, simd, lut(resolution = N, ranges = [low..high, ...])";
+
+  /// Parse the value of `mode = ...`
+  pub fn parse(expr: &Expr) -> syn::Result<Self> {
+    let invalid = || syn::Error::new_spanned(expr, format!("Invalid `mode`. Please use one of {{ {} }}.", Mode::VARIANTS_LIST));
+
+    match expr {
+      Expr::Path(p) if p.path.is_ident("arithmetic") => Ok(Mode::Arithmetic),
+      Expr::Path(p) if p.path.is_ident("compact")    => Ok(Mode::Compact),
+      Expr::Path(p) if p.path.is_ident("simd")       => {
+        if cfg!(feature = "simd") { Ok(Mode::Simd) }
+        else { Err(syn::Error::new_spanned(expr, "`mode = simd` requires the `simd` feature of `const_cge`.")) }
+      },
+      Expr::Call(ExprCall { func, args, .. }) if matches!(&**func, Expr::Path(p) if p.path.is_ident("lut")) => {
+        let mut resolution = None;
+        let mut ranges = None;
+
+        for arg in args {
+          let (name, value) = match arg {
+            Expr::Assign(ExprAssign { left, right, .. }) => match &**left {
+              Expr::Path(p) if p.path.get_ident().is_some() => (p.path.get_ident().unwrap().to_string(), &**right),
+              _ => return Err(invalid())
+            },
+            _ => return Err(invalid())
+          };
+
+          match name.as_ref() {
+            "resolution" => resolution = Some(match value {
+              Expr::Lit(ExprLit { lit: Lit::Int(n), .. }) => n.base10_parse::<usize>()?,
+              _ => return Err(syn::Error::new_spanned(value, "Expected an integer `resolution` (samples per input)."))
+            }),
+            "ranges" => ranges = Some(match value {
+              Expr::Array(a) => a.elems.iter().map(parse_range).collect::<syn::Result<Vec<_>>>()?,
+              _ => return Err(syn::Error::new_spanned(value, "Expected `ranges = [low..high, ...]` (one range per input)."))
+            }),
+            _ => return Err(invalid())
+          }
+        }
+
+        let resolution = resolution.ok_or_else(|| syn::Error::new_spanned(expr, "`lut` requires a `resolution` (samples per input)."))?;
+        let ranges = ranges.ok_or_else(|| syn::Error::new_spanned(expr, "`lut` requires `ranges = [low..high, ...]` (one range per input)."))?;
+        if resolution < 2 { return Err(syn::Error::new_spanned(expr, "`lut` requires a `resolution` of at least 2.")); }
+
+        Ok(Mode::Lut { resolution, ranges })
+      },
+      _ => Err(invalid())
+    }
+  }
+
+  /// Provides the tokens of the mode, suitable for interpolation as the value of `mode = ...`
+  pub fn token(&self) -> TokenStream {
+    match self {
+      Mode::Arithmetic => quote!(arithmetic),
+      Mode::Compact    => quote!(compact),
+      Mode::Simd       => quote!(simd),
+      Mode::Lut { resolution, ranges } => {
+        let resolution = proc_macro2::Literal::usize_unsuffixed(*resolution);
+        let ranges = ranges.iter().map(|&(low, high)| {
+          let (low, high) = (float_token(low), float_token(high));
+          quote!(#low..#high)
+        });
+        quote!(lut(resolution = #resolution, ranges = [#(#ranges),*]))
+      }
+    }
+  }
+}
+
+/// A numeric literal (negative numbers are a literal preceded by `-`)
+pub fn float_token(value: f64) -> TokenStream {
+  let magnitude = proc_macro2::Literal::f64_unsuffixed(value.abs());
+  if value.is_sign_negative() { quote!(-#magnitude) } else { quote!(#magnitude) }
+}
+
+/// Parse `low..high`
+fn parse_range(expr: &Expr) -> syn::Result<(f64, f64)> {
+  match expr {
+    Expr::Range(ExprRange { from: Some(from), to: Some(to), limits: RangeLimits::HalfOpen(_), .. }) => {
+      let (low, high) = (parse_float(from)?, parse_float(to)?);
+      if low < high { Ok((low, high)) } else { Err(syn::Error::new_spanned(expr, "Input range must be non-empty (`low..high`, with `low < high`).")) }
+    },
+    _ => Err(syn::Error::new_spanned(expr, "Expected an input range like `-1.0..1.0`."))
+  }
+}
+
+/// Parse a (possibly negated) numeric literal
+pub fn parse_float(expr: &Expr) -> syn::Result<f64> {
+  match expr {
+    Expr::Lit(ExprLit { lit: Lit::Float(f), .. }) => f.base10_parse(),
+    Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) => i.base10_parse(),
+    Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. }) => Ok(-parse_float(expr)?),
+    _ => Err(syn::Error::new_spanned(expr, "Expected a number."))
+  }
+}
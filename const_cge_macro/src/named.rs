@@ -0,0 +1,126 @@
+use cge::{Network, WithRecurrentState};
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, format_ident};
+use serde_json::Value;
+use syn::Visibility;
+use crate::numeric_type::NumericType;
+
+/// Names of the inputs or outputs (`key` is `input_names` / `output_names`): from the attribute (or the netcrate's defaults), or else the `extra` section of the CGE file.
+fn names(source: &str, key: &str, specified: Option<&Vec<String>>) -> Option<Vec<String>> {
+  if let Some(names) = specified { return Some(names.clone()); }
+
+  let cge: Value = serde_json::from_str(source).expect("Failed to parse CGE data");
+  let names = cge["network"].get("extra")?.get(key)?;
+  let names = names.as_array().unwrap_or_else(|| panic!("`extra.{}` in the CGE file must be a list of strings.", key));
+  Some(names.iter().map(|name| name.as_str().unwrap_or_else(|| panic!("`extra.{}` in the CGE file must be a list of strings.", key)).to_string()).collect())
+}
+
+/// The field for a name (`"gyro_x"` is `gyro_x`, `"Gyro X"` is `gyro_x`, `"3d"` is `_3d`)
+fn field(name: &str) -> Ident {
+  let field = name
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|word| !word.is_empty())
+    .map(str::to_lowercase)
+    .collect::<Vec<_>>()
+    .join("_");
+
+  let field = match field.chars().next() {
+    None => panic!("Name `{:?}` has no letters or digits, so cannot name a field.", name),
+    Some(c) if c.is_numeric() => format!("_{}", field),
+    Some(_) => field
+  };
+
+  syn::parse_str(&field).unwrap_or_else(|_| panic!("Name `{:?}` becomes `{}`, which is not a valid field name.", name, field))
+}
+
+/// A `#[repr(C)]` struct with one field per name (in order), converting to and from the raw array.
+fn named_struct(name: &Ident, visibility: &Visibility, kind: &str, names: &[String], numeric_type: NumericType) -> TokenStream {
+  let fields: Vec<Ident> = names.iter().map(|name| field(name)).collect();
+  for (index, field) in fields.iter().enumerate() {
+    if let Some(other) = fields[..index].iter().position(|other| other == field) {
+      panic!("{} names `{:?}` and `{:?}` both become the field `{}`.", kind, names[other], names[index], field);
+    }
+  }
+
+  let named = format_ident!("{}{}s", name, kind);
+  let numeric_token = numeric_type.token();
+  let count = names.len();
+  let indices = 0..count;
+  let documentation = format!(
+"The {lower}s of [`{name}`], by name (`#[repr(C)]`, fields in {lower} order).
+- Converts to and from `[{numeric}; {count}]` (`From`), see `{name}::evaluate_named`.",
+    lower = kind.to_lowercase(), numeric = numeric_token, name = name, count = count
+  );
+  let field_documentation = names.iter().enumerate().map(|(index, name)| format!("{} {} (`{}`)", kind, index, name));
+
+  quote! {
+    #[doc = #documentation]
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    #visibility struct #named {
+      #( #[doc = #field_documentation] pub #fields: #numeric_token ),*
+    }
+
+    impl From<[#numeric_token; #count]> for #named {
+      fn from(values: [#numeric_token; #count]) -> Self {
+        Self { #(#fields: values[#indices]),* }
+      }
+    }
+
+    impl From<#named> for [#numeric_token; #count] {
+      fn from(named: #named) -> Self {
+        [#(named.#fields),*]
+      }
+    }
+  }
+}
+
+/// Generate `<Name>Inputs` / `<Name>Outputs` (when the inputs / outputs are named) and `Name::evaluate_named`,
+/// which takes and returns them (or the raw array, for the side without names).
+pub fn named_io(
+  name:             &Ident,
+  visibility:       &Visibility,
+  source:           &str,
+  input_names:      Option<&Vec<String>>,
+  output_names:     Option<&Vec<String>>,
+  recurrency_count: usize,
+  numeric_type:     NumericType
+) -> Option<(TokenStream, TokenStream)> {
+  let (input_names, output_names) = (names(source, "input_names", input_names), names(source, "output_names", output_names));
+  if input_names.is_none() && output_names.is_none() { return None; }
+
+  let (network, _, _) = Network::<f64>::load_str::<()>(source, WithRecurrentState(false))
+    .unwrap_or_else(|e| panic!("Failed to load network ({})", e));
+  let numeric_token = numeric_type.token();
+
+  // each side is either a named struct (converted to / from the array), or the raw array
+  let side = |kind: &str, names: &Option<Vec<String>>, count: usize| match names {
+    Some(names) => {
+      if names.len() != count { panic!("{} {} names were given, but the network has {} {}s.", names.len(), kind.to_lowercase(), count, kind.to_lowercase()); }
+      let named = format_ident!("{}{}s", name, kind);
+      (Some(named_struct(name, visibility, kind, names, numeric_type)), quote!(#named), quote!(.into()))
+    },
+    None => (None, quote!([#numeric_token; #count]), quote!())
+  };
+
+  let (inputs_struct, inputs_type, inputs_conversion) = side("Input", &input_names, network.num_inputs());
+  let (outputs_struct, outputs_type, outputs_conversion) = side("Output", &output_names, network.num_outputs());
+
+  let (self_argument, evaluate) = if recurrency_count == 0 {
+    (quote!(), quote!(Self::evaluate))
+  } else {
+    (quote!(&mut self,), quote!(self.evaluate))
+  };
+
+  let evaluate_named = quote! {
+    /// Evaluate the network with named inputs and outputs (rather than positions in an array, which are easily miswired).
+    pub fn evaluate_named(#self_argument inputs: &#inputs_type) -> #outputs_type {
+      let inputs: [#numeric_token; #name::INPUT_COUNT] = (*inputs) #inputs_conversion;
+      let mut outputs = [0.0; #name::OUTPUT_COUNT];
+      #evaluate(&inputs, &mut outputs);
+      outputs #outputs_conversion
+    }
+  };
+
+  Some((quote! { #inputs_struct #outputs_struct }, evaluate_named))
+}
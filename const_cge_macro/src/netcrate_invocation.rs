@@ -1,19 +1,146 @@
+use cge::{Network, WithRecurrentState};
+use proc_macro2::TokenStream;
+use quote::{quote, format_ident};
+use syn::{Token, LitStr, Ident, Path};
+use crate::macro_core::{Config, CgeType};
+
+const USAGE: &str = "Usage: `netcrate!(public_name = \"path/to/file.cge\");` or `netcrate!(namespace::public_name = \"path/to/file.cge\", version = \"1.0.0\");`";
+
 pub struct NetcrateInvocation {
-  pub name: syn::Ident,
-  pub path: String
+  /// Modules the network is exported under (e.g. `vision` in `vision::ocr`), may be empty
+  pub namespace: Vec<Ident>,
+  pub name:      Ident,
+  pub path:      String,
+
+  // author-declared metadata (all optional)
+  pub version:     Option<String>,
+  pub license:     Option<String>,
+  pub description: Option<String>,
+
+  /// Defaults for the network's attribute arguments (e.g. `numeric_type = f32`), users can override them
+  /// - `input_names` / `output_names` are also the labels of the metadata.
+  pub defaults:    Config,
+}
+
+impl NetcrateInvocation {
+  pub const ARGUMENTS_LIST: &'static str = "version, license, description";
 }
 
 impl syn::parse::Parse for NetcrateInvocation {
   fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-    let name  = input.parse::<syn::Ident>()
-      .expect("Usage: `netcrate!(public_name = \"path/to/file.cge\");`");
-
-    let _ = input.parse::<syn::Token![=]>()
-      .expect("Usage: `netcrate!(public_name = \"path/to/file.cge\");`");
-    
-    let path = input.parse::<syn::LitStr>()
-      .expect("Usage: `netcrate!(public_name = \"path/to/file.cge\");`").value();
-    
-    Ok(NetcrateInvocation { name, path })
+    let path = Path::parse_mod_style(input).unwrap_or_else(|_| panic!("{}", USAGE));
+    if path.leading_colon.is_some() { panic!("Netcrate names are relative to the netcrate (no leading `::`). {}", USAGE); }
+
+    let mut segments: Vec<Ident> = path.segments.into_iter().map(|segment| segment.ident).collect();
+    let name = segments.pop().unwrap();
+    let namespace = segments;
+
+    let _ = input.parse::<Token![=]>().unwrap_or_else(|_| panic!("{}", USAGE));
+    let path = input.parse::<LitStr>().unwrap_or_else(|_| panic!("{}", USAGE)).value();
+
+    let mut invocation = NetcrateInvocation {
+      namespace, name, path,
+      version: None, license: None, description: None,
+      defaults: Config::new(CgeType::Direct(String::new()))
+    };
+
+    // remaining arguments (`name = value`, in any order)
+    while input.parse::<Option<Token![,]>>()?.is_some() {
+      // tolerate a trailing comma
+      if input.is_empty() { break; }
+
+      let arg_name = input.parse::<Ident>()?;
+      let _: Token![=] = input.parse()
+        .unwrap_or_else(|_| panic!("Expected '=' after `{}` argument.", arg_name));
+
+      let string = |input: syn::parse::ParseStream| input.parse::<LitStr>()
+        .unwrap_or_else(|_| panic!("Expected a string after `{} = `.", arg_name))
+        .value();
+
+      match arg_name.to_string().as_ref() {
+        "version"     => invocation.version     = Some(string(input)),
+        "license"     => invocation.license     = Some(string(input)),
+        "description" => invocation.description = Some(string(input)),
+        other if Config::ARGUMENTS_LIST.split(", ").any(|argument| argument == other) => invocation.defaults.parse_argument(&arg_name, input)?,
+        _ => return Err(syn::Error::new_spanned(&arg_name, format!("Nonsense `netcrate!` argument `{}`. Expected one of {{ {}, {} }}.", arg_name, NetcrateInvocation::ARGUMENTS_LIST, Config::ARGUMENTS_LIST)))
+      }
+    }
+
+    Ok(invocation)
   }
-}
\ No newline at end of file
+}
+
+/// The name of the macro a netcrate exports for the network `namespace::name`.
+/// - `#[macro_export]` hoists every macro to the root of the netcrate, so namespaced networks
+///   get a mangled (hidden) name instead, e.g. `vision::ocr` is exported as `__netcrate__vision__ocr`.
+pub fn exported_macro(namespace: &[Ident], name: &Ident) -> Ident {
+  if namespace.is_empty() { return name.clone(); }
+
+  let mangled: String = namespace
+    .iter()
+    .chain(Some(name))
+    .map(|segment| format!("__{}", segment))
+    .collect();
+
+  format_ident!("__netcrate{}", mangled)
+}
+
+/// Resolve the path a user wrote (e.g. `zoo::vision::ocr`) to the macro the netcrate exported for it.
+/// - The first segment is the netcrate, the last is the network, and anything between is its namespace.
+/// - Single-segment paths (e.g. `ocr`, after `use zoo::*`) are used as-is.
+pub fn resolve(path: &Path) -> TokenStream {
+  let segments: Vec<Ident> = path.segments.iter().map(|segment| segment.ident.clone()).collect();
+  if segments.len() < 2 { return quote!(#path); }
+
+  let leading_colon = path.leading_colon;
+  let netcrate = &segments[0];
+  let exported = exported_macro(&segments[1..segments.len() - 1], &segments[segments.len() - 1]);
+
+  quote!(#leading_colon #netcrate::#exported)
+}
+
+/// The `const_cge::Metadata` of the network exported by `invocation` (checking its labels against the network).
+pub fn metadata(invocation: &NetcrateInvocation, cge_data: &str) -> TokenStream {
+  let (network, cge_metadata, _) = Network::<f64>::load_str::<()>(cge_data, WithRecurrentState(false))
+    .unwrap_or_else(|e| panic!("Failed to load CGE file `{}` ({})", invocation.path, e));
+
+  let name = invocation.namespace
+    .iter()
+    .chain(Some(&invocation.name))
+    .map(Ident::to_string)
+    .collect::<Vec<_>>()
+    .join("::");
+
+  let (input_count, output_count) = (network.num_inputs(), network.num_outputs());
+  let labels = |labels: &Option<Vec<String>>, kind: &str, count: usize| match labels {
+    Some(labels) => {
+      if labels.len() != count { panic!("`{}` declares {} {} labels, but the network has {} {}s.", name, labels.len(), kind, count, kind); }
+      quote!(Some(&[#(#labels),*]))
+    },
+    None => quote!(None)
+  };
+
+  let option = |value: Option<&String>| match value {
+    Some(value) => quote!(Some(#value)),
+    None => quote!(None)
+  };
+
+  let version       = option(invocation.version.as_ref());
+  let license       = option(invocation.license.as_ref());
+  let description   = option(invocation.description.as_ref().or(cge_metadata.description.as_ref()));
+  let input_labels  = labels(&invocation.defaults.input_names, "input", input_count);
+  let output_labels = labels(&invocation.defaults.output_names, "output", output_count);
+
+  quote! {
+    const_cge::Metadata {
+      name:          #name,
+      version:       #version,
+      license:       #license,
+      description:   #description,
+      input_count:   #input_count,
+      output_count:  #output_count,
+      input_labels:  #input_labels,
+      output_labels: #output_labels,
+    }
+  }
+}
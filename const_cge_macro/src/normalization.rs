@@ -0,0 +1,79 @@
+use serde_json::{Value, json};
+
+/// Fold input normalization (`input * scale + offset`, per input) into the network, returning the new CGE data.
+/// - `scale` / `offset` default to the `input_scale` / `input_offset` arrays of the CGE file's `extra` section (if any).
+/// - Every input gene (weight `w`) becomes an input gene with weight `w * scale` and, if the offset is nonzero,
+///   a bias gene with value `w * offset` (its neuron gets one more input). No runtime cost at all.
+/// - Returns `None` if there is nothing to fold.
+pub fn fold_normalization(source: &str, scale: Option<&[f64]>, offset: Option<&[f64]>) -> Option<String> {
+  let mut cge: Value = serde_json::from_str(source).expect("Failed to parse CGE data");
+  let network = &mut cge["network"];
+
+  // read defaults from the `extra` section
+  let extra_vector = |key: &str| -> Option<Vec<f64>> {
+    let vector = network.get("extra")?.get(key)?;
+    let vector = vector.as_array().unwrap_or_else(|| panic!("`extra.{}` in the CGE file must be a list of numbers.", key));
+    Some(vector.iter().map(|x| x.as_f64().unwrap_or_else(|| panic!("`extra.{}` in the CGE file must be a list of numbers.", key))).collect())
+  };
+
+  let scale = scale.map(<[f64]>::to_vec).or_else(|| extra_vector("input_scale"));
+  let offset = offset.map(<[f64]>::to_vec).or_else(|| extra_vector("input_offset"));
+  if scale.is_none() && offset.is_none() { return None; }
+
+  let genome = network["genome"].as_array_mut().expect("Corrupt CGE: missing genome");
+  let input_count = genome
+    .iter()
+    .filter(|gene| gene["kind"] == "input")
+    .map(|gene| gene["id"].as_u64().expect("Corrupt CGE: input gene without an ID") as usize + 1)
+    .max()
+    .unwrap_or(0);
+
+  let check_length = |argument: &str, vector: &Option<Vec<f64>>| if let Some(vector) = vector {
+    if vector.len() != input_count { panic!("`{}` has {} elements, but the network has {} inputs.", argument, vector.len(), input_count); }
+  };
+  check_length("input_scale", &scale);
+  check_length("input_offset", &offset);
+
+  // walk the genome (prefix order: each neuron is followed by its inputs), tracking the parent of every gene
+  let mut folded = Vec::with_capacity(genome.len());
+  let mut parents: Vec<(usize, usize)> = vec![]; // (index in `folded`, inputs remaining)
+
+  for gene in genome.drain(..) {
+    let parent = parents.last_mut().map(|(index, remaining)| { *remaining -= 1; *index });
+    while let Some(&(_, 0)) = parents.last() { parents.pop(); }
+
+    let mut bias = None;
+    let mut gene = gene;
+
+    match gene["kind"].as_str() {
+      Some("neuron") => {
+        let inputs = gene["num_inputs"].as_u64().expect("Corrupt CGE: neuron without `num_inputs`") as usize;
+        folded.push(gene);
+        parents.push((folded.len() - 1, inputs));
+        continue;
+      },
+      Some("input") => {
+        let id = gene["id"].as_u64().unwrap() as usize;
+        let weight = gene["weight"].as_f64().expect("Corrupt CGE: input gene without a weight");
+        let (scale, offset) = (scale.as_ref().map_or(1.0, |s| s[id]), offset.as_ref().map_or(0.0, |o| o[id]));
+
+        gene["weight"] = json!(weight * scale);
+        if offset != 0.0 {
+          let parent = parent.unwrap_or_else(|| panic!("Cannot fold `input_offset` into an input which is directly an output of the network."));
+          bias = Some((parent, weight * offset));
+        }
+      },
+      _ => {}
+    }
+
+    folded.push(gene);
+    if let Some((parent, value)) = bias {
+      folded.push(json!({ "kind": "bias", "value": value }));
+      let inputs = folded[parent]["num_inputs"].as_u64().unwrap();
+      folded[parent]["num_inputs"] = json!(inputs + 1);
+    }
+  }
+
+  *genome = folded;
+  Some(cge.to_string())
+}
@@ -3,7 +3,7 @@ use quote::quote;
 
 /// Supported numeric types
 /// TODO: support f16, u64, u32, u16, u8, i64, i32, i16, i8, etc. with automatic conversion
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum NumericType {
   Float64,
   Float32
@@ -28,6 +28,15 @@ impl NumericType {
     }
   }
 
+  /// Relative tolerance used when comparing generated code against the (always `f64`) `cge` runtime.
+  /// - The activation backends are not bitwise identical to `cge`, and `f32` also loses precision in every parameter.
+  pub fn tolerance(&self) -> f64 {
+    match self {
+      NumericType::Float64 => 1e-9,
+      NumericType::Float32 => 1e-3,
+    }
+  }
+
   /// Take standard high-precision f64 input and convert it to the desired numeric type
   pub fn naive_conversion(&self, base: f64) -> TokenStream {
     match self {
@@ -0,0 +1,208 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, format_ident};
+use syn::{Token, Path, Visibility, parenthesized, punctuated::Punctuated};
+use crate::numeric_type::NumericType;
+
+const USAGE: &str = "Usage: `pipeline!(Robot = Perception => Planner => Motor);`, or with groups splitting / concatenating outputs: `pipeline!(pub Robot = Perception => (Planner, Reflex) => Motor);`";
+
+/// `impl const_cge::Stage` for a compiled network (so it can be a stage of a `pipeline!`).
+pub fn stage_impl(name: &Ident, numeric_type: NumericType, recurrency_count: usize) -> TokenStream {
+  let numeric_token = numeric_type.token();
+  let evaluate = if recurrency_count == 0 { quote!(Self::evaluate) } else { quote!(self.evaluate) };
+
+  quote! {
+    impl const_cge::Stage for #name {
+      type Numeric = #numeric_token;
+
+      #[inline]
+      fn evaluate_stage(&mut self, inputs: &[#numeric_token], outputs: &mut [#numeric_token]) {
+        let inputs = <&[#numeric_token; #name::INPUT_COUNT] as ::core::convert::TryFrom<_>>::try_from(inputs)
+          .expect("`evaluate_stage` needs `INPUT_COUNT` inputs");
+        let outputs = <&mut [#numeric_token; #name::OUTPUT_COUNT] as ::core::convert::TryFrom<_>>::try_from(outputs)
+          .expect("`evaluate_stage` needs `OUTPUT_COUNT` outputs");
+
+        #evaluate(inputs, outputs);
+      }
+    }
+  }
+}
+
+/// `[vis] Name = Stage => (Stage, Stage) => Stage`
+pub struct PipelineInvocation {
+  pub visibility: Visibility,
+  pub name:       Ident,
+
+  /// Each stage is a group of networks (usually just one), evaluated side by side:
+  /// they split the previous stage's outputs between them (in order), and their outputs are concatenated.
+  pub stages:     Vec<Vec<Path>>,
+}
+
+impl syn::parse::Parse for PipelineInvocation {
+  fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+    let visibility = input.parse::<Visibility>()?;
+    let name = input.parse::<Ident>().unwrap_or_else(|_| panic!("{}", USAGE));
+    let _ = input.parse::<Token![=]>().unwrap_or_else(|_| panic!("{}", USAGE));
+
+    let mut stages = vec![];
+    loop {
+      let stage = if input.peek(syn::token::Paren) {
+        let content;
+        parenthesized!(content in input);
+        Punctuated::<Path, Token![,]>::parse_terminated(&content)?.into_iter().collect()
+      } else {
+        vec![input.parse::<Path>().unwrap_or_else(|_| panic!("{}", USAGE))]
+      };
+
+      if stage.is_empty() { panic!("Empty pipeline stage `()`. {}", USAGE); }
+      stages.push(stage);
+
+      if input.parse::<Option<Token![=>]>>()?.is_none() { break; }
+    }
+
+    // tolerate a trailing comma
+    let _ = input.parse::<Option<Token![,]>>()?;
+    if !input.is_empty() { panic!("Unexpected tokens after the last pipeline stage. {}", USAGE); }
+    if stages.len() < 2 { panic!("A pipeline needs at least two stages. {}", USAGE); }
+
+    Ok(PipelineInvocation { visibility, name, stages })
+  }
+}
+
+/// `perception` for `Perception`, `motor_v2` for `nets::MotorV2`
+fn field_name(path: &Path) -> String {
+  let name = path.segments.last().unwrap().ident.to_string();
+  let mut field = String::new();
+  for (index, c) in name.chars().enumerate() {
+    if c.is_uppercase() && index != 0 && !field.ends_with('_') { field.push('_'); }
+    field.extend(c.to_lowercase());
+  }
+  field
+}
+
+/// `A::COUNT + B::COUNT + ...` (the width of a group of networks, `0` if empty)
+fn width(group: &[Path], count: &Ident) -> TokenStream {
+  if group.is_empty() { return quote!(0); }
+  quote!(#(#group::#count)+*)
+}
+
+/// Generate the pipeline: a struct owning every stage (and its recurrent state),
+/// whose `evaluate` chains the stages through stack buffers, checking every connection at compile time.
+pub fn pipeline(invocation: PipelineInvocation) -> TokenStream {
+  let PipelineInvocation { visibility, name, stages } = invocation;
+  let (input_count, output_count) = (format_ident!("INPUT_COUNT"), format_ident!("OUTPUT_COUNT"));
+
+  // one field per network (numbered, if a network appears more than once)
+  let mut fields: Vec<Vec<Ident>> = vec![];
+  let mut taken: Vec<String> = vec![];
+  for stage in &stages {
+    fields.push(stage.iter().map(|path| {
+      let base = field_name(path);
+      let mut field = base.clone();
+      let mut suffix = 2;
+      while taken.contains(&field) { field = format!("{}_{}", base, suffix); suffix += 1; }
+      taken.push(field.clone());
+      // keywords (`struct Type;` becomes `type`) get a trailing underscore
+      syn::parse_str::<Ident>(&field).unwrap_or_else(|_| format_ident!("{}_", field))
+    }).collect());
+  }
+
+  let first = &stages[0];
+  let last = &stages[stages.len() - 1];
+  let numeric = {
+    let path = &first[0];
+    quote!(<#path as const_cge::Stage>::Numeric)
+  };
+  let pipeline_inputs = width(first, &input_count);
+  let pipeline_outputs = width(last, &output_count);
+
+  // every connection must match, at compile time
+  let checks = stages.windows(2).map(|pair| {
+    let (outputs, inputs) = (width(&pair[0], &output_count), width(&pair[1], &input_count));
+    let describe = |group: &[Path]| {
+      let names: Vec<String> = group.iter().map(|path| quote!(#path).to_string().replace(' ', "")).collect();
+      if names.len() == 1 { names[0].clone() } else { format!("({})", names.join(", ")) }
+    };
+    let message = format!(
+      "pipeline `{}`: the outputs of `{}` do not match the inputs of `{}` (OUTPUT_COUNT != INPUT_COUNT)",
+      name, describe(&pair[0]), describe(&pair[1])
+    );
+
+    quote!(const _: () = assert!(#outputs == #inputs, #message);)
+  });
+
+  // evaluate each stage into its own buffer (the last writes straight into `outputs`)
+  let mut previous = quote!(inputs);
+  let steps = stages.iter().zip(&fields).enumerate().map(|(index, (group, fields))| {
+    let is_last = index == stages.len() - 1;
+    let buffer = format_ident!("stage_{}", index);
+
+    let declaration = if is_last { quote!() } else {
+      let width = width(group, &output_count);
+      quote!(let mut #buffer = [0.0; #width];)
+    };
+    let destination = if is_last { quote!(outputs) } else { quote!(#buffer) };
+
+    let calls = group.iter().zip(fields).enumerate().map(|(member, (path, field))| {
+      let (input_offset, output_offset) = (width(&group[..member], &input_count), width(&group[..member], &output_count));
+      quote! {
+        self.#field.evaluate_stage(
+          &#previous[#input_offset..#input_offset + #path::INPUT_COUNT],
+          &mut #destination[#output_offset..#output_offset + #path::OUTPUT_COUNT]
+        );
+      }
+    }).collect::<Vec<_>>();
+
+    previous = quote!(#buffer);
+    quote! { #declaration #(#calls)* }
+  }).collect::<Vec<_>>();
+
+  let all_paths: Vec<&Path> = stages.iter().flatten().collect();
+  let all_fields: Vec<&Ident> = fields.iter().flatten().collect();
+  let field_documentation = all_paths.iter().map(|path| format!("Stage [`{}`]", quote!(#path).to_string().replace(' ', "")));
+  let documentation = "A pipeline of networks, evaluated in order (generated by `pipeline!`).
+- Owns every stage (and its recurrent state), outputs flow between stages through stack buffers.
+- Every connection between stages is checked at compile time.";
+
+  quote! {
+    #[doc = #documentation]
+    #[derive(Clone, Copy, Default)]
+    #visibility struct #name {
+      #( #[doc = #field_documentation] pub #all_fields: #all_paths ),*
+    }
+
+    #(#checks)*
+
+    impl #name {
+      /// The number of inputs to the pipeline (of its first stage). Provided for convenience (const).
+      pub const INPUT_COUNT: usize = #pipeline_inputs;
+
+      /// The number of outputs from the pipeline (of its last stage). Provided for convenience (const).
+      pub const OUTPUT_COUNT: usize = #pipeline_outputs;
+
+      /// Evaluate every stage in order, each reading the outputs of the one before.
+      pub fn evaluate(&mut self, inputs: &[#numeric; #pipeline_inputs], outputs: &mut [#numeric; #pipeline_outputs]) {
+        use const_cge::Stage;
+        #(#steps)*
+      }
+
+      /// Reset every stage to its initial (all-zero) recurrent state.
+      pub fn reset(&mut self) {
+        *self = Self::default();
+      }
+    }
+
+    impl const_cge::Stage for #name {
+      type Numeric = #numeric;
+
+      #[inline]
+      fn evaluate_stage(&mut self, inputs: &[#numeric], outputs: &mut [#numeric]) {
+        let inputs = <&[#numeric; #pipeline_inputs] as ::core::convert::TryFrom<_>>::try_from(inputs)
+          .expect("`evaluate_stage` needs `INPUT_COUNT` inputs");
+        let outputs = <&mut [#numeric; #pipeline_outputs] as ::core::convert::TryFrom<_>>::try_from(outputs)
+          .expect("`evaluate_stage` needs `OUTPUT_COUNT` outputs");
+
+        self.evaluate(inputs, outputs);
+      }
+    }
+  }
+}
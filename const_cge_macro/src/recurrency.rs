@@ -12,6 +12,7 @@ pub fn identify_recurrence(network: &Network<f64>) -> HashMap<NeuronId, usize> {
   // literally just list all `Recurrent` genes that exist in the network. 
   // The ID field is the /source/ of the recurrence,
   // and our job is to number these neurons tightly (they will become indices in an array)
+  // - several jumpers may share a source, each source is numbered once (in order of first appearance, like `cge`)
   let mut recurrence_table = HashMap::new();
 
   network
@@ -21,8 +22,10 @@ pub fn identify_recurrence(network: &Network<f64>) -> HashMap<NeuronId, usize> {
       cge::gene::Gene::RecurrentJumper(g) => Some(g.source_id()),
       _ => None
     })
-    .enumerate()
-    .for_each(|(index, id)| { recurrence_table.insert(id, index); });
+    .for_each(|id| {
+      let index = recurrence_table.len();
+      recurrence_table.entry(id).or_insert(index);
+    });
   
   recurrence_table
 }
\ No newline at end of file
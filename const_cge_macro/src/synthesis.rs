@@ -1,30 +1,161 @@
-use std::collections::HashSet;
-use cge::{Network, gene::Gene, Activation, WithRecurrentState};
+use std::{collections::HashMap, ops::Range};
+use cge::{Network, gene::{Gene, NeuronId}, network::NeuronInfo, Activation, WithRecurrentState};
 use proc_macro2::TokenStream;
 use quote::quote;
-use crate::{recurrency, evaluator::{self, ResultNames}, macro_core::{Invocation, CgeType}, numeric_type::NumericType};
+use crate::{recurrency, evaluator::{self, ResultNames, OperationCounts, Precision}, macro_core::{Invocation, CgeType, Config}, numeric_type::NumericType, mode::Mode, lut::synthesize_lut, compact::synthesize_compact, layered::synthesize_layered, fingerprint::fingerprint, invocation_parser::manifest_relative, interface::check_interface, normalization::fold_normalization, head::with_head, verification::Approximation, bank::lane_evaluation};
 
 /// - Number of recurrent neural states we must retain (0 implies nonrecurrent architecture)
+/// - The CGE data the network was loaded from
+/// - The tag of the network's fingerprint (tags the type of snapshots of its recurrent state)
 /// - A bundle of rust code to be interpolated in the final step
 pub struct Synthesis {
   pub recurrency_count:     usize,
+  pub source:               String,
+  pub fingerprint:          u64,
   pub documentation:        TokenStream,
   pub persistence_field:    TokenStream,
   pub associated_constants: TokenStream,
   pub persistence_methods:  TokenStream,
   pub evaluate_function:    TokenStream,
+  pub wrapper_functions:    TokenStream,
+
+  /// Set when `evaluate` only approximates the network (`mode = lut(...)`), for `verify`
+  pub approximation:        Option<Approximation>,
+
+  /// The body of `<Name>Bank<N>::evaluate_chunk` (empty for nonrecurrent networks, which get no bank)
+  pub lanes:                TokenStream,
+}
+
+/// Structural properties of the network, and the cost of the code we generate for it.
+struct Statistics {
+  neuron_count:         usize,
+  connection_count:     usize,
+  bias_count:           usize,
+  forward_jumper_count: usize,
+  depth:                usize,
+  operations:           OperationCounts,
+}
+
+impl Statistics {
+  fn new(network: &Network<f64>, operations: OperationCounts) -> Self {
+    let genome = network.genome();
+    let count = |predicate: fn(&Gene<f64>) -> bool| genome.iter().filter(|g| predicate(g)).count();
+
+    Statistics {
+      neuron_count:         count(Gene::is_neuron),
+      // every gene is an input to some neuron, except for the output neurons themselves
+      connection_count:     genome.iter().filter_map(Gene::as_neuron).map(|n| n.num_inputs()).sum(),
+      bias_count:           count(Gene::is_bias),
+      forward_jumper_count: count(Gene::is_forward_jumper),
+      depth:                longest_paths(genome, network.neuron_info_map(), 0..genome.len(), &mut HashMap::new()).into_iter().max().unwrap_or(0),
+      operations
+    }
+  }
+}
+
+/// Length (in neurons) of the longest path leading into each root of the subgenome in `range`.
+/// - Forward jumpers continue the path through their source neuron, recurrent jumpers end it
+///   (they read last evaluation's value).
+fn longest_paths(
+  genome:      &[Gene<f64>],
+  neuron_info: &HashMap<NeuronId, NeuronInfo>,
+  range:       Range<usize>,
+  memo:        &mut HashMap<NeuronId, usize>
+) -> Vec<usize> {
+  let mut stack = vec![];
+
+  for gene in genome[range].iter().rev() {
+    let length = match gene {
+      Gene::Neuron(n) => {
+        let inputs = stack.split_off(stack.len() - n.num_inputs());
+        1 + inputs.into_iter().max().unwrap_or(0)
+      },
+      Gene::ForwardJumper(f) => {
+        let id = f.source_id();
+        match memo.get(&id) {
+          Some(&length) => length,
+          None => {
+            let length = longest_paths(genome, neuron_info, neuron_info[&id].subgenome_range(), memo)[0];
+            memo.insert(id, length);
+            length
+          }
+        }
+      },
+      Gene::Input(_) | Gene::Bias(_) | Gene::RecurrentJumper(_) => 0
+    };
+
+    stack.push(length);
+  }
+
+  stack
 }
 
-/// Load network
-fn load_network(cge_path: &str) -> Network<f64> {
-  let network = Network::<f64>::load_file::<(), _>(&cge_path, cge::WithRecurrentState(false));
+/// Load network (and the CGE data it was loaded from)
+pub fn load_network(cge_path: &str) -> (Network<f64>, String) {
+  let data = std::fs::read_to_string(manifest_relative(cge_path)).unwrap_or_else(|e| panic!("Failed to open CGE file ({})", e));
+  let network = Network::<f64>::load_str::<()>(&data, cge::WithRecurrentState(false));
   match network {
-    Ok((n, _, _)) => n,
+    Ok((n, _, _)) => (n, data),
     Err(e) => panic!("Failed to open CGE file ({})", e)
   }
 }
 
-fn activation_path(activation: Activation, numeric_type: NumericType) -> TokenStream {
+/// The `persistence` field of a recurrent network, and the methods accessing it (both empty for nonrecurrent networks).
+/// - `fingerprint` tags the type of snapshots (see `Self::snapshot`).
+pub fn persistence(numeric_type: NumericType, recurrency_count: usize, fingerprint: u64) -> (TokenStream, TokenStream) {
+  let numeric_token = numeric_type.token();
+
+  if recurrency_count == 0 {
+    (quote!(), quote!())
+  } else {
+    (
+      quote!(persistence: [#numeric_token; #recurrency_count],),
+      quote!(
+        /// Create network instance with internal recurrent state.
+        /// - Useful for "restoring a snapshot" of the network's recurrent state.
+        pub fn with_recurrent_state(persistence: &[#numeric_token; #recurrency_count]) -> Self {
+          Self { persistence: *persistence }
+        }
+        
+        /// Overwrite the networks recurrent state with the given one.
+        /// - Useful for "restoring a snapshot" of the network's recurrent state (even if you don't know what any part of it really means).
+        pub fn set_recurrent_state(&mut self, persistence: &[#numeric_token; #recurrency_count]) {
+          self.persistence = persistence.clone();
+        }
+
+        /// Get a reference to the internal recurrent state.
+        pub fn recurrent_state(&self) -> &[#numeric_token; #recurrency_count] {
+          &self.persistence
+        }
+
+        /// Get a mutable reference to the internal recurrent state (for modifications).
+        /// - This is advanced usage. Recurrent state will likely be _opaque_ (unclear to you what parts of the state do what - welcome to the party),
+        /// but this method is made available for flexibility.
+        pub fn recurrent_state_mut(&mut self) -> &mut [#numeric_token; #recurrency_count] {
+          &mut self.persistence
+        }
+
+        /// Reset the recurrent state to all zeros (as if newly constructed with `Self::default()`).
+        pub fn reset(&mut self) {
+          self.persistence = [0.0; #recurrency_count];
+        }
+
+        /// Take a snapshot of the recurrent state.
+        /// - The snapshot's type is tagged with this network's fingerprint, so it can only be restored into this network.
+        pub fn snapshot(&self) -> const_cge::State<#numeric_token, #recurrency_count, #fingerprint> {
+          const_cge::State::new(self.persistence)
+        }
+
+        /// Restore recurrent state from a snapshot (see `Self::snapshot`).
+        pub fn restore(&mut self, state: &const_cge::State<#numeric_token, #recurrency_count, #fingerprint>) {
+          self.persistence = *state.as_array();
+        }
+      )
+    )
+  }
+}
+
+pub fn activation_path(activation: Activation, numeric_type: NumericType) -> TokenStream {
   let numeric_type = numeric_type.token();
 
   match activation {
@@ -39,20 +170,53 @@ fn activation_path(activation: Activation, numeric_type: NumericType) -> TokenSt
   }
 }
 
+/// Is the activation implemented as a `const fn` by every backend?
+fn activation_is_const(activation: Activation) -> bool {
+  matches!(activation, Activation::Linear | Activation::UnitStep | Activation::Relu | Activation::Sign | Activation::SoftSign)
+}
+
+/// Resolve `fma` and `accumulate` (these only shape the arithmetic of `mode = arithmetic`).
+fn precision(invocation: &Invocation) -> Precision {
+  let Config { numeric_type, fma, accumulate, ref mode, .. } = invocation.config;
+  let accumulate = accumulate.unwrap_or(numeric_type);
+
+  if (fma || accumulate != numeric_type) && !matches!(mode, Mode::Arithmetic) {
+    panic!("`fma` and `accumulate` only apply to `mode = arithmetic`.");
+  }
+  if accumulate.size_of() < numeric_type.size_of() {
+    panic!("`accumulate` must be at least as wide as `numeric_type` (accumulating in a narrower type only loses precision).");
+  }
+
+  Precision { fma, accumulate }
+}
+
 /// Load, evaluate, and synthesize an implementation.
 pub fn synthesize(invocation: &Invocation) -> Synthesis {
   // construct a network from a file or a literal (module invocations cannot reach this point)
-  let network = match invocation.config.cge {
-    CgeType::File(ref path)   => load_network(path),
+  let (network, source) = match invocation.config.cge {
+    CgeType::File(ref path) | CgeType::Environment { ref path, .. } => load_network(path),
     CgeType::Direct(ref data) => {
       let (net, _, _) = Network::<f64>::load_str::<()>(data, WithRecurrentState(false))
-        .expect("Your input doesn't look like a path (or the file isn't accessible to me). I've inferred that you might be trying to supply CGE data directly as a string, but the input also doesn't parse as valid CGE.");
+        .unwrap_or_else(|e| panic!("The CGE data doesn't parse as valid CGE ({}). If you meant a path to a CGE file, it doesn't exist (paths are relative to the crate root), say `file = \"...\"` for a clearer error.", e));
 
-      net
+      (net, data.clone())
     },
     CgeType::Module(_) => unreachable!()
   };
 
+  // fold input normalization into the input weights (a different network, as far as everything downstream is concerned)
+  let folded = fold_normalization(&source, invocation.config.input_scale.as_deref(), invocation.config.input_offset.as_deref());
+  let normalized = folded.is_some();
+  let (network, source) = match folded {
+    Some(folded) => {
+      let (network, _, _) = Network::<f64>::load_str::<()>(&folded, WithRecurrentState(false))
+        .unwrap_or_else(|e| panic!("Failed to fold input normalization ({})", e));
+
+      (network, folded)
+    },
+    None => (network, source)
+  };
+
   // literally a list of floating point operations as rust code
   let mut computations_list = vec![];
   let mut computations_end = vec![];
@@ -68,23 +232,14 @@ pub fn synthesize(invocation: &Invocation) -> Synthesis {
 
   let recurrency_table = recurrency::identify_recurrence(&network);
   let recurrency_count = recurrency_table.len();
-  let input_count = {
-    // The number of inputs to a network is the number of unique input IDs
-    // found among all Input genes in the genome.
-    let mut input_ids = HashSet::new();
-
-    network
-      .genome()
-      .iter()
-      .filter_map(|g| match g {
-        Gene::Input(i) => Some(i.id()),
-        _ => None
-      })
-      .for_each(|id| { input_ids.insert(id.as_usize()); });
-    
-    input_ids.len()
-  };
-  let output_count = evaluator::evaluate(
+  check_interface(&invocation.config, invocation.recurrency_constraint, &network, recurrency_count);
+
+  // The number of inputs to a network is one more than the largest input ID found among all Input genes
+  // in the genome (IDs index the input array, and some may go unused), exactly as `cge` sees it.
+  let input_count = network.num_inputs();
+  let mut operations = OperationCounts::default();
+  let precision = precision(invocation);
+  let outputs = evaluator::evaluate(
     &network.genome(),
     &network.neuron_info_map(),
     0..network.len(),
@@ -94,63 +249,92 @@ pub fn synthesize(invocation: &Invocation) -> Synthesis {
     &mut computations_list, 
     &mut computations_end, 
     &mut ResultNames::default(),
+    &mut HashMap::new(),
+    &mut operations,
     &recurrency_table,
     invocation.config.numeric_type,
+    precision,
     activation_fn_path
   ).expect("Corrupt CGE: network appears to have no outputs");
 
-  let numeric_token = invocation.config.numeric_type.token();
-  let numeric_bytes = invocation.config.numeric_type.size_of();
-
-  // generate a 'persistence' field and access methods (only if neccessary)
-  let (persistence_field, persistence_methods) = {
-    if recurrency_count == 0 {
-      (quote!(), quote!())
-    } else {
-      (
-        quote!(persistence: [#numeric_token; #recurrency_count],),
-        quote!(
-          /// Create network instance with internal recurrent state.
-          /// - Useful for "restoring a snapshot" of the network's recurrent state.
-          pub fn with_recurrent_state(persistence: &[#numeric_token; #recurrency_count]) -> Self {
-            Self { persistence: *persistence }
-          }
-          
-          /// Overwrite the networks recurrent state with the given one.
-          /// - Useful for "restoring a snapshot" of the network's recurrent state (even if you don't know what any part of it really means).
-          pub fn set_recurrent_state(&mut self, persistence: &[#numeric_token; #recurrency_count]) {
-            self.persistence = persistence.clone();
-          }
+  // store network outputs in the output buffer
+  let output_count = outputs.len();
+  computations_list.extend(outputs.iter().enumerate().map(|(index, value)| quote!(outputs[#index] = #value;)));
 
-          /// Get a reference to the internal recurrent state.
-          pub fn recurrent_state(&self) -> &[#numeric_token; #recurrency_count] {
-            &self.persistence
-          }
+  // recurrent networks get a bank, running the same arithmetic for many instances at once
+  let lanes = if recurrency_count == 0 { quote!() } else {
+    lane_evaluation(
+      &network,
+      &recurrency_table,
+      invocation.config.numeric_type,
+      precision,
+      activation_path(activation, invocation.config.numeric_type),
+      |index, value| quote!(outputs[#index][lane] = #value;)
+    )
+  };
 
-          /// Get a mutable reference to the internal recurrent state (for modifications).
-          /// - This is advanced usage. Recurrent state will likely be _opaque_ (unclear to you what parts of the state do what - welcome to the party),
-          /// but this method is made available for flexibility.
-          pub fn recurrent_state_mut(&mut self) -> &mut [#numeric_token; #recurrency_count] {
-            &mut self.persistence
-          }
-        )
-      )
+  // alternatively, replace all that arithmetic with another implementation of `evaluate` (and describe it)
+  let (mode_function, mode_documentation, operations, approximation) = match invocation.config.mode {
+    Mode::Arithmetic => (None, None, operations, None),
+    Mode::Lut { resolution, ref ranges } => {
+      if recurrency_count != 0 { panic!("Network is recurrent, `mode = lut(...)` cannot capture recurrent state."); }
+      let lut = synthesize_lut(&network, output_count, invocation.config.numeric_type, resolution, ranges);
+      let approximation = Approximation { ranges: ranges.clone(), max_error: lut.max_error };
+      (Some(lut.evaluate_function), Some(lut.documentation), lut.operations, Some(approximation))
+    },
+    Mode::Compact => {
+      // same arithmetic, just interpreted
+      let compact = synthesize_compact(&network, &recurrency_table, invocation.config.numeric_type, activation_path(activation, invocation.config.numeric_type));
+      (Some(compact.evaluate_function), Some(compact.documentation), operations, None)
+    },
+    Mode::Simd => {
+      let layered = synthesize_layered(&network, &recurrency_table, invocation.config.numeric_type, activation_path(activation, invocation.config.numeric_type));
+      (Some(layered.evaluate_function), Some(layered.documentation), layered.operations, None)
     }
   };
+  let head = invocation.config.head.as_ref();
+  let is_const = recurrency_count == 0 && activation_is_const(activation) && mode_function.is_none() && !precision.fma && head.is_none();
+
+  let Statistics {
+    neuron_count,
+    connection_count,
+    bias_count,
+    forward_jumper_count,
+    depth,
+    operations: OperationCounts { muls: mul_count, adds: add_count, activations: activation_calls }
+  } = Statistics::new(&network, operations);
+
+  let numeric_token = invocation.config.numeric_type.token();
+  let fingerprint = fingerprint(&network, invocation.config.numeric_type);
+  let (fingerprint_bytes, topology_bytes, fingerprint_hex) = (fingerprint.full, fingerprint.topology, fingerprint.hex());
+  let fingerprint = fingerprint.tag();
+  let numeric_bytes = invocation.config.numeric_type.size_of();
+
+  // generate a 'persistence' field and access methods (only if neccessary)
+  let (persistence_field, persistence_methods) = persistence(invocation.config.numeric_type, recurrency_count, fingerprint);
 
   // dynamically generate doc comments (with usage examples!) that match _this particlar network_.
   let documentation = {
     let build_info = format!(
-"{source_statement}- {recurrency_statement}",
+"{source_statement}{normalization_statement}- {recurrency_statement}
+- Structure
+  - {neuron_count} neurons, {connection_count} connections ({bias_count} biases, {forward_jumper_count} forward jumpers), depth {depth}
+  - `Self::evaluate` performs {mul_count} multiplications, {add_count} additions, and {activation_calls} activation calls{mode_statement}{head_statement}
+- Fingerprint (`Self::FINGERPRINT`): `{fingerprint_hex}`",
     source_statement = match invocation.config.cge {
       CgeType::File(ref path) => format!("- Compiled from CGE file: `{}`\n", path),
+      CgeType::Environment { ref variable, ref path } => format!("- Compiled from CGE file: `{}` (chosen by `${}` at build time)\n", path, variable),
       CgeType::Direct(_) => "".into(),
       CgeType::Module(_) => "".into()
     }, 
+    normalization_statement = if normalized { "- Inputs are normalized (`input * scale + offset`), folded into the input weights\n" } else { "" },
     recurrency_statement = if recurrency_count == 0 {
+format!(
 "No recurrency detected
   - network is stateless (a ZST)
-  - `Self::evaluate` is static.".into()
+  - `Self::evaluate` is static{const_statement}.",
+        const_statement = if is_const { ", and a `const fn`" } else { "" }
+      )
     } else {
       format!(
 "Network is recurrent (stateful)
@@ -161,6 +345,19 @@ pub fn synthesize(invocation: &Invocation) -> Synthesis {
         byte_count = recurrency_count * numeric_bytes,
         byte_plural = if recurrency_count * numeric_bytes == 1 { "" } else { "s" },
       )
+    },
+    mode_statement = match (mode_documentation, precision.accumulate != invocation.config.numeric_type, precision.fma) {
+      (Some(documentation), _, _) => format!("\n- {}", documentation),
+      (None, false, false) => String::new(),
+      (None, widened, fused) => format!(
+        "\n- Neuron sums {accumulation}{fusion}",
+        accumulation = if widened { format!("accumulated in `{}` (with parameters at that precision)", precision.accumulate.token()) } else { "".into() },
+        fusion = match (widened, fused) { (true, true) => ", using fused multiply-adds", (false, true) => "use fused multiply-adds", _ => "" },
+      )
+    },
+    head_statement = match head {
+      Some(head) => format!("\n- Outputs pass through a `{}` head (`Self::evaluate_raw` skips it)", head.name()),
+      None => String::new()
     }
   );
 
@@ -209,13 +406,53 @@ pub fn synthesize(invocation: &Invocation) -> Synthesis {
     /// The size of internal state of the network (number of numeric elements). Provided for convenience (const).
     /// - NOTE: This constant is _always available_, and will be zero for non-recurrent networks.
     pub const PERSISTENT_SIZE: usize = #recurrency_count;
+
+    /// The number of neurons in the network. Provided for convenience (const).
+    pub const NEURON_COUNT: usize = #neuron_count;
+
+    /// The number of weighted connections in the network (inputs, biases, jumpers, and neuron-to-neuron links). Provided for convenience (const).
+    pub const CONNECTION_COUNT: usize = #connection_count;
+
+    /// The number of bias connections in the network. Provided for convenience (const).
+    pub const BIAS_COUNT: usize = #bias_count;
+
+    /// The number of forward jumper connections in the network. Provided for convenience (const).
+    pub const FORWARD_JUMPER_COUNT: usize = #forward_jumper_count;
+
+    /// The number of neurons along the longest path from an input to an output. Provided for convenience (const).
+    /// - Recurrent connections are not followed (they carry the previous evaluation's value).
+    pub const DEPTH: usize = #depth;
+
+    /// The number of multiplications performed by a single call to `Self::evaluate`. Provided for convenience (const).
+    pub const MUL_COUNT: usize = #mul_count;
+
+    /// The number of additions performed by a single call to `Self::evaluate`. Provided for convenience (const).
+    pub const ADD_COUNT: usize = #add_count;
+
+    /// The number of activation function calls made by a single call to `Self::evaluate`. Provided for convenience (const).
+    /// - A subnetwork reached through a forward jumper before its neurons have been computed is evaluated early, and counted again.
+    pub const ACTIVATION_CALLS: usize = #activation_calls;
+
+    /// A stable SHA-256 hash of the canonicalized genome (structure and every parameter), activation function, and numeric type.
+    /// - Not affected by metadata, formatting, or compilation options (`mode`, `fma`, ...).
+    /// - Pin the exact network you validated with `const_cge::same_fingerprint` (e.g. one from a netcrate, which may change between versions).
+    pub const FINGERPRINT: [u8; 32] = [#(#fingerprint_bytes),*];
+
+    /// Like `Self::FINGERPRINT`, but ignoring weights and biases: networks with the same structure, activation function, and numeric type share it
+    /// (and so can share recurrent state layouts, or weight files).
+    pub const TOPOLOGY_HASH: [u8; 32] = [#(#topology_bytes),*];
   };
   
-  let evaluate_function = {
+  let evaluate_function = if let Some(mode_function) = mode_function {
+    mode_function
+  } else {
     // should the `evaluate` function get a `&mut self`, or can it be a static function?
     let self_argument = if recurrency_count == 0 { quote!() } else { quote!(&mut self,) };
     let numeric_comment = format!("-  - how fast your target hardware can perform numeric (`{}`) operations", numeric_token);
 
+    // stateless networks built only from `const` activations can be evaluated at compile time (e.g. to build lookup tables)
+    let constness = if is_const { quote!(const) } else { quote!() };
+
     quote! {
       /// Evaluate the network for a single input vector.
       /// 
@@ -224,19 +461,106 @@ pub fn synthesize(invocation: &Invocation) -> Synthesis {
       /// - should be near the fundamental speed/size limit given:
       /// -  - what LLVM can _safely_ emit (optimization through elision, reordering, vectorization, register reuse, etc)
       #[doc = #numeric_comment]
-      pub fn evaluate(#self_argument inputs: &[#numeric_token; #input_count], outputs: &mut [#numeric_token; #output_count]) {
+      pub #constness fn evaluate(#self_argument inputs: &[#numeric_token; #input_count], outputs: &mut [#numeric_token; #output_count]) {
         #(#computations_list)*
         #(#computations_end)*
       }
     }
   };
 
+  // optionally, post-process the outputs (whatever the mode)
+  let evaluate_function = match head {
+    Some(head) => with_head(evaluate_function, head, invocation.config.numeric_type, recurrency_count, input_count, output_count),
+    None => evaluate_function
+  };
+
+  // `evaluate`, but for inputs and outputs that aren't already arrays of the right size
+  let wrapper_functions = {
+    let self_argument = if recurrency_count == 0 { quote!() } else { quote!(&mut self,) };
+    let evaluate = if recurrency_count == 0 { quote!(Self::evaluate) } else { quote!(self.evaluate) };
+
+    // every step written out, stepping a local copy of the network (its state is written back once)
+    let unrolled = if recurrency_count == 0 { quote!() } else {
+      quote! {
+        /// Evaluate `K` timesteps (e.g. a block of samples), like `evaluate_sequence` with a length known at compile time.
+        /// - The `K` steps are written out one after another, rather than looped over (`K` is at most `const_cge::unroll::MAX_UNROLLED`).
+        /// - The steps advance a local copy of the network, whose state is written back to `self` once, at the end.
+        #[inline]
+        pub fn evaluate_unrolled<const K: usize>(&mut self, inputs: &[[#numeric_token; #input_count]; K], outputs: &mut [[#numeric_token; #output_count]; K])
+        where
+          [(); K]: const_cge::unroll::Unrolled
+        {
+          let mut network = *self;
+          <[(); K] as const_cge::unroll::Unrolled>::steps(|step| network.evaluate(&inputs[step], &mut outputs[step]));
+          *self = network;
+        }
+      }
+    };
+
+    quote! {
+      /// Evaluate the network, reading inputs from and writing outputs to slices (e.g. DMA or ring buffers).
+      /// - Fails without evaluating if `inputs.len() != Self::INPUT_COUNT` or `outputs.len() != Self::OUTPUT_COUNT`.
+      pub fn try_evaluate_slice(#self_argument inputs: &[#numeric_token], outputs: &mut [#numeric_token]) -> Result<(), const_cge::DimensionError> {
+        let found = inputs.len();
+        let inputs = <&[#numeric_token; #input_count] as ::core::convert::TryFrom<_>>::try_from(inputs)
+          .map_err(|_| const_cge::DimensionError::Inputs { expected: #input_count, found })?;
+
+        let found = outputs.len();
+        let outputs = <&mut [#numeric_token; #output_count] as ::core::convert::TryFrom<_>>::try_from(outputs)
+          .map_err(|_| const_cge::DimensionError::Outputs { expected: #output_count, found })?;
+
+        #evaluate(inputs, outputs);
+        Ok(())
+      }
+
+      /// Evaluate the network, reading inputs from any iterator, and returning the outputs.
+      /// - The iterator must yield exactly `Self::INPUT_COUNT` elements, otherwise this fails without evaluating.
+      /// - At most `Self::INPUT_COUNT + 1` elements are read (so endless iterators fail, rather than never returning).
+      pub fn evaluate_iter<I: IntoIterator<Item = #numeric_token>>(#self_argument inputs: I) -> Result<[#numeric_token; #output_count], const_cge::DimensionError> {
+        let mut buffer = [0.0; #input_count];
+        let mut found = 0;
+        for value in inputs.into_iter().take(#input_count + 1) {
+          if let Some(slot) = buffer.get_mut(found) { *slot = value; }
+          found += 1;
+        }
+
+        if found != #input_count {
+          return Err(const_cge::DimensionError::Inputs { expected: #input_count, found });
+        }
+
+        let mut outputs = [0.0; #output_count];
+        #evaluate(&buffer, &mut outputs);
+        Ok(outputs)
+      }
+
+      /// Evaluate the network once per element of `inputs` (in order), writing the corresponding element of `outputs`.
+      /// - Fails without evaluating if `inputs` and `outputs` have different lengths.
+      pub fn evaluate_sequence(#self_argument inputs: &[[#numeric_token; #input_count]], outputs: &mut [[#numeric_token; #output_count]]) -> Result<(), const_cge::DimensionError> {
+        if inputs.len() != outputs.len() {
+          return Err(const_cge::DimensionError::Sequence { inputs: inputs.len(), outputs: outputs.len() });
+        }
+
+        for (inputs, outputs) in inputs.iter().zip(outputs) {
+          #evaluate(inputs, outputs);
+        }
+        Ok(())
+      }
+
+      #unrolled
+    }
+  };
+
   Synthesis {
     recurrency_count,
+    source,
+    fingerprint,
     documentation,
     persistence_field,
     associated_constants,
     persistence_methods,
     evaluate_function,
+    wrapper_functions,
+    approximation,
+    lanes,
   }
 }
\ No newline at end of file
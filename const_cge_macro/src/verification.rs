@@ -0,0 +1,155 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, format_ident};
+use crate::numeric_type::NumericType;
+
+/// Number of consecutive evaluations per trial, so recurrent state gets exercised.
+const RECURRENT_STEPS: usize = 8;
+
+/// How far an approximate `evaluate` (`mode = lut(...)`) may stray from the exact network, and where it is valid.
+pub struct Approximation {
+  /// One range per input (inputs outside of them are clamped, so they are only sampled from within)
+  pub ranges:    Vec<(f64, f64)>,
+
+  /// The largest error measured at compile time (absolute, on top of the numeric type's tolerance)
+  pub max_error: f64,
+}
+
+/// Measured errors come from a handful of points per cell, so the test allows for some more in between.
+const APPROXIMATION_MARGIN: f64 = 2.0;
+
+impl Approximation {
+  /// How much error generated tests allow, on top of the numeric type's tolerance.
+  pub fn allowance(&self) -> f64 {
+    self.max_error * APPROXIMATION_MARGIN
+  }
+
+  /// Are `inputs` inside the ranges (where the approximation is valid)?
+  pub fn contains(&self, inputs: &[f64]) -> bool {
+    inputs.iter().zip(&self.ranges).all(|(&x, &(low, high))| low <= x && x <= high)
+  }
+}
+
+/// What the compiled network is checked against: networks in the `cge` runtime, and how their outputs are combined.
+pub struct Reference {
+  /// The CGE data of every network (one, unless the network is an ensemble)
+  pub sources: Vec<String>,
+
+  /// The expected outputs (`Vec<f64>`), from `members` (every network's outputs, `Vec<Vec<f64>>`, in order)
+  pub combine: TokenStream,
+}
+
+impl Reference {
+  /// A single network, whose outputs are expected as is.
+  pub fn network(source: &str) -> Self {
+    Reference { sources: vec![source.to_string()], combine: quote!(members.into_iter().next().unwrap()) }
+  }
+}
+
+/// Generate a `#[cfg(test)]` module which checks the compiled network against the `cge` runtime `Network`.
+/// - `trials` random input sequences (each element in [-1, +1], or within the `approximation`'s ranges) are fed to both, from a cleared state.
+/// - Requires `cge` as a dev-dependency of the crate invoking the macro.
+/// - `evaluate` is the compiled function to check (`evaluate_raw` if the network has an output head).
+/// - `reference` is usually `Reference::network(source)`, ensembles are checked against all of their members.
+pub fn verification_module(
+  name:             &Ident,
+  evaluate:         &Ident,
+  reference:        &Reference,
+  recurrency_count: usize,
+  numeric_type:     NumericType,
+  trials:           usize,
+  approximation:    Option<&Approximation>
+) -> TokenStream {
+  let module_name = format_ident!("__const_cge_verify_{}", name);
+  let test_name = format_ident!("{}_matches_cge_runtime", name.to_string().to_lowercase());
+  let numeric_token = numeric_type.token();
+  let Reference { sources, combine } = reference;
+  let source_count = sources.len();
+  let tolerance = numeric_type.tolerance();
+
+  // approximations only promise to be close, and only inside their ranges
+  let (lows, highs, allowance) = match approximation {
+    Some(approximation) => (
+      approximation.ranges.iter().map(|&(low, _)| low).collect(),
+      approximation.ranges.iter().map(|&(_, high)| high).collect(),
+      approximation.allowance()
+    ),
+    None => (vec![], vec![], 0.0)
+  };
+  let sample = if approximation.is_some() {
+    quote! {
+      const LOWS: [f64; #name::INPUT_COUNT] = [#(#lows),*];
+      const HIGHS: [f64; #name::INPUT_COUNT] = [#(#highs),*];
+      for (index, x) in inputs.iter_mut().enumerate() {
+        *x = (LOWS[index] + (HIGHS[index] - LOWS[index]) * (random() + 1.0) * 0.5) as #numeric_token;
+      }
+    }
+  } else {
+    quote!(inputs.iter_mut().for_each(|x: &mut #numeric_token| *x = random() as #numeric_token);)
+  };
+
+  // stateless networks don't benefit from more than one step, and have a static `evaluate`
+  let (steps, instance, evaluate) = if recurrency_count == 0 {
+    (1, quote!(), quote!(#name::#evaluate))
+  } else {
+    (RECURRENT_STEPS, quote!(let mut net = #name::default();), quote!(net.#evaluate))
+  };
+
+  quote! {
+    #[cfg(test)]
+    #[allow(non_snake_case)]
+    mod #module_name {
+      extern crate std; // `no_std` crates still link `std` for tests, but do not name it
+      use super::#name;
+
+      /// The network (or every member of the ensemble), as the `cge` runtime will see it.
+      const CGE: [&str; #source_count] = [#(#sources),*];
+
+      #[test]
+      fn #test_name() {
+        let mut runtimes = CGE.map(|cge| {
+          ::cge::Network::<f64>::load_str::<()>(cge, ::cge::WithRecurrentState(false))
+            .expect("Failed to load network into the `cge` runtime")
+            .0
+        });
+
+        // xorshift64, fixed seed (failures must be reproducible), uniform in [-1, +1]
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut random = move || {
+          seed ^= seed << 13;
+          seed ^= seed >> 7;
+          seed ^= seed << 17;
+          ((seed >> 11) as f64 / (1u64 << 53) as f64) * 2.0 - 1.0
+        };
+
+        for trial in 0..#trials {
+          #instance
+          runtimes.iter_mut().for_each(|runtime| runtime.clear_state());
+
+          for step in 0..#steps {
+            let mut inputs = [0.0; #name::INPUT_COUNT];
+            let mut outputs = [0.0; #name::OUTPUT_COUNT];
+            #sample
+
+            #evaluate(&inputs, &mut outputs);
+
+            // feed the runtime exactly what the compiled network saw
+            let runtime_inputs = inputs.iter().map(|&x| x as f64).collect::<std::vec::Vec<f64>>();
+            let members = runtimes.iter_mut()
+              .map(|runtime| runtime.evaluate(&runtime_inputs).expect("`cge` runtime rejected the inputs").to_vec())
+              .collect::<std::vec::Vec<std::vec::Vec<f64>>>();
+            let expected: std::vec::Vec<f64> = #combine;
+
+            for (index, (&compiled, &expected)) in outputs.iter().zip(&expected).enumerate() {
+              let compiled = compiled as f64;
+              assert!(
+                (compiled - expected).abs() <= #tolerance * (1.0 + expected.abs()) + #allowance,
+                "trial {}, step {}, output {}: compiled network produced {}, `cge` runtime produced {}",
+                trial, step, index, compiled, expected
+              );
+            }
+          }
+        }
+      }
+    }
+  }
+}
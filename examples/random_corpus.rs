@@ -0,0 +1,179 @@
+//! Generates the corpus of random (but valid) CGE networks in `test_inputs/random_corpus/`:
+//! `cargo run --example random_corpus` (the corpus is checked in, re-run this after changing the generator).
+//! - `src/tests.rs` includes `harness.rs`, which compiles every network with `verify`,
+//!   checking the generated code against `cge::Network::evaluate` (in `mode = arithmetic`, `mode = compact`, and `mode = simd` with the `simd` feature).
+//! - Deterministic (fixed seed, no dependencies), so failures are reproducible.
+use std::{fmt::Write as _, fs, path::Path};
+
+/// How many networks to generate
+const NETWORK_COUNT: usize = 100;
+
+/// How many random trials `verify` runs per network
+const TRIALS_PER_NETWORK: usize = 64;
+
+/// Deepest nesting of neurons (not counting jumpers)
+const MAX_DEPTH: usize = 4;
+
+/// Every activation `cge` knows about (as spelled in CGE files)
+const ACTIVATIONS: [&str; 8] = ["linear", "unitstep", "relu", "sign", "sigmoid", "tanh", "softsign", "bentidentity"];
+
+/// Where the corpus lives (relative to the crate root, which is where the `network` macro resolves paths from)
+const CORPUS_DIR: &str = "test_inputs/random_corpus";
+
+fn main() {
+  let corpus_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(CORPUS_DIR);
+  fs::create_dir_all(&corpus_dir).expect("Failed to create random corpus directory");
+
+  let mut rng = Rng(0x853c_49e6_748f_ea9b);
+  let mut harness = String::from("// generated by `cargo run --example random_corpus`, do not edit\n");
+
+  for index in 0..NETWORK_COUNT {
+    let activation = ACTIVATIONS[index % ACTIVATIONS.len()];
+    let cge = RandomNetwork::generate(&mut rng).to_cge(activation, index);
+
+    let file = format!("net_{:03}.cge", index);
+    fs::write(corpus_dir.join(&file), cge).expect("Failed to write random network");
+
+    // with the `simd` feature, the layered codegen is checked too
+    writeln!(
+      harness,
+      "mod net_{index:03} {{ use crate as const_cge; #[const_cge::network({path:?}, verify = {TRIALS_PER_NETWORK})] struct Net{index:03}; #[const_cge::network({path:?}, verify = {TRIALS_PER_NETWORK}, mode = compact)] struct Compact{index:03}; #[cfg(feature = \"simd\")] #[const_cge::network({path:?}, verify = {TRIALS_PER_NETWORK}, mode = simd)] struct Simd{index:03}; }}",
+      path = format!("./{}/{}", CORPUS_DIR, file),
+    ).unwrap();
+  }
+
+  fs::write(corpus_dir.join("harness.rs"), harness).expect("Failed to write random corpus harness");
+}
+
+/// xorshift64
+struct Rng(u64);
+
+impl Rng {
+  fn next(&mut self) -> u64 {
+    self.0 ^= self.0 << 13;
+    self.0 ^= self.0 >> 7;
+    self.0 ^= self.0 << 17;
+    self.0
+  }
+
+  /// Uniform in `low..high`
+  fn range(&mut self, low: usize, high: usize) -> usize { low + (self.next() % (high - low) as u64) as usize }
+
+  /// Uniform in [-1, +1]
+  fn weight(&mut self) -> f64 { ((self.next() >> 11) as f64 / (1u64 << 53) as f64) * 2.0 - 1.0 }
+
+  fn chance(&mut self, probability: f64) -> bool { (self.weight() + 1.0) / 2.0 < probability }
+}
+
+/// A gene, before it is written out.
+/// - Jumpers are decided after the whole tree exists (their sources depend on neuron depths).
+enum Node {
+  Neuron    { id: usize, depth: usize, weight: f64, inputs: Vec<Node> },
+  Input     { id: usize, weight: f64 },
+  Bias      { value: f64 },
+  Forward   { source: usize, weight: f64 },
+  Recurrent { source: usize, weight: f64 },
+
+  /// Placeholder for either kind of jumper
+  Jumper { parent_depth: usize },
+}
+
+struct RandomNetwork {
+  outputs: Vec<Node>,
+}
+
+impl RandomNetwork {
+  fn generate(rng: &mut Rng) -> Self {
+    let input_count = rng.range(1, 5);
+    let output_count = rng.range(1, 4);
+    let mut next_id = 0;
+
+    let mut outputs = (0..output_count)
+      .map(|_| Self::neuron(rng, 0, input_count, &mut next_id))
+      .collect::<Vec<_>>();
+
+    // every neuron is a candidate source for a jumper
+    let mut neurons = vec![];
+    for output in &outputs { Self::collect_neurons(output, &mut neurons); }
+    for output in &mut outputs { Self::resolve_jumpers(output, rng, &neurons); }
+
+    RandomNetwork { outputs }
+  }
+
+  fn neuron(rng: &mut Rng, depth: usize, input_count: usize, next_id: &mut usize) -> Node {
+    let id = *next_id;
+    *next_id += 1;
+
+    let inputs = (0..rng.range(1, 5))
+      .map(|_| {
+        if depth + 1 < MAX_DEPTH && rng.chance(0.35) {
+          Self::neuron(rng, depth + 1, input_count, next_id)
+        } else if rng.chance(0.3) {
+          Node::Jumper { parent_depth: depth }
+        } else if rng.chance(0.2) {
+          Node::Bias { value: rng.weight() }
+        } else {
+          // input IDs are not necessarily dense
+          Node::Input { id: rng.range(0, input_count), weight: rng.weight() }
+        }
+      })
+      .collect();
+
+    Node::Neuron { id, depth, weight: rng.weight(), inputs }
+  }
+
+  fn collect_neurons(node: &Node, neurons: &mut Vec<(usize, usize)>) {
+    if let Node::Neuron { id, depth, inputs, .. } = node {
+      neurons.push((*id, *depth));
+      for input in inputs { Self::collect_neurons(input, neurons); }
+    }
+  }
+
+  /// Replace every jumper placeholder with a forward jumper (source must be deeper than the parent),
+  /// or a recurrent jumper (any source).
+  fn resolve_jumpers(node: &mut Node, rng: &mut Rng, neurons: &[(usize, usize)]) {
+    if let Node::Neuron { inputs, .. } = node {
+      for input in inputs {
+        if let Node::Jumper { parent_depth } = *input {
+          let deeper = neurons.iter().filter(|(_, depth)| *depth > parent_depth).collect::<Vec<_>>();
+
+          *input = if !deeper.is_empty() && rng.chance(0.5) {
+            let (source, _) = *deeper[rng.range(0, deeper.len())];
+            Node::Forward { source, weight: rng.weight() }
+          } else {
+            let (source, _) = neurons[rng.range(0, neurons.len())];
+            Node::Recurrent { source, weight: rng.weight() }
+          };
+        }
+
+        Self::resolve_jumpers(input, rng, neurons);
+      }
+    }
+  }
+
+  fn to_cge(&self, activation: &str, index: usize) -> String {
+    let mut genes = vec![];
+    for output in &self.outputs { Self::write(output, &mut genes); }
+
+    format!(
+      r#"{{"version":"1","network":{{"metadata":{{"description":"Random network #{index} (generated by examples/random_corpus.rs)"}},"activation":"{activation}","genome":[{genome}],"recurrent_state":null,"extra":null}}}}"#,
+      genome = genes.join(",")
+    )
+  }
+
+  /// Write genes in CGE order (a neuron, followed by the genes of each of its inputs)
+  fn write(node: &Node, genes: &mut Vec<String>) {
+    genes.push(match node {
+      Node::Neuron { id, weight, inputs, .. } => format!(r#"{{"kind":"neuron","id":{id},"num_inputs":{},"weight":{weight:?}}}"#, inputs.len()),
+      Node::Input { id, weight }              => format!(r#"{{"kind":"input","id":{id},"weight":{weight:?}}}"#),
+      Node::Bias { value }                    => format!(r#"{{"kind":"bias","value":{value:?}}}"#),
+      Node::Forward { source, weight }        => format!(r#"{{"kind":"forwardjumper","source_id":{source},"weight":{weight:?}}}"#),
+      Node::Recurrent { source, weight }      => format!(r#"{{"kind":"recurrentjumper","source_id":{source},"weight":{weight:?}}}"#),
+      Node::Jumper { .. }                     => unreachable!("jumpers are resolved before writing"),
+    });
+
+    if let Node::Neuron { inputs, .. } = node {
+      for input in inputs { Self::write(input, genes); }
+    }
+  }
+}
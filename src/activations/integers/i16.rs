@@ -1,5 +1,5 @@
 pub const fn linear(x: i16)        -> i16 { x }
-pub       fn threshold(x: i16)     -> i16 {
+pub const fn threshold(x: i16)     -> i16 {
   // twos complement, leading bit is sign (negative = 0b1, positive = 0b0)
   // sign extend through bit shift, negate, bitwise AND.
   // const SHIFT_AMOUNT: usize = (core::mem::size_of::<i16>() * 8) - 1;
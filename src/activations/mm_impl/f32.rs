@@ -1,12 +1,18 @@
 #[allow(unused_imports)]
 use micromath::F32Ext;
-use libm::tanhf as libm_tanh;
+use libm::{tanhf as libm_tanh, fmaf as libm_fma};
 
 pub const fn linear(x: f32)        -> f32 { x }
-pub       fn threshold(x: f32)     -> f32 { if x > 0.0 { 1.0 } else { 0.0 } }
-pub       fn relu(x: f32)          -> f32 { if x > 0.0 { x } else { 0.0 } } 
-pub       fn sign(x: f32)          -> f32 { if x > 0.0 { 1.0 } else if x == 0.0 { 0.0 } else { -1.0 } }
+pub const fn threshold(x: f32)     -> f32 { if x > 0.0 { 1.0 } else { 0.0 } }
+pub const fn relu(x: f32)          -> f32 { if x > 0.0 { x } else { 0.0 } } 
+pub const fn sign(x: f32)          -> f32 { if x > 0.0 { 1.0 } else if x == 0.0 { 0.0 } else { -1.0 } }
 pub       fn sigmoid(x: f32)       -> f32 { 1.0 / (1.0 + (-x).exp()) }
 pub       fn tanh(x: f32)          -> f32 { libm_tanh(x) }
-pub       fn soft_sign(x: f32)     -> f32 { x / (1.0 + x.abs()) }
-pub       fn bent_identity(x: f32) -> f32 { ((((x * x) + 1.0).sqrt() - 1.0) / 2.0) + x }
\ No newline at end of file
+pub const fn soft_sign(x: f32)     -> f32 { x / (1.0 + if x < 0.0 { -x } else { x }) }
+pub       fn bent_identity(x: f32) -> f32 { ((((x * x) + 1.0).sqrt() - 1.0) / 2.0) + x }
+
+/// `x * y + z`, with a single rounding (used by `fma = true`, micromath only approximates it)
+pub       fn mul_add(x: f32, y: f32, z: f32) -> f32 { libm_fma(x, y, z) }
+
+/// `e^x` (used by `head = softmax`)
+pub       fn exp(x: f32) -> f32 { x.exp() }
@@ -0,0 +1,92 @@
+//! A tiny bytecode interpreter, shared by every network compiled with `mode = compact`.
+//! - The macro emits each network as a `static` [`Program`], so adding another network to a binary costs data, not instructions.
+//! - The interpreter is generic over the numeric type, so one copy exists per numeric type (not per network).
+use core::ops::{Add, Mul};
+
+/// One step of a compiled network.
+/// - Operates on a stack of values, exactly mirroring the order of arithmetic in `mode = arithmetic`
+///   (the results are bitwise identical).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Instruction<T> {
+  /// Push `weight * inputs[index]`
+  Input     { index: u16, weight: T },
+
+  /// Push `value`
+  Bias      { value: T },
+
+  /// Push `weight * persistence[state]` (the previous evaluation's output of some neuron)
+  Recurrent { state: u16, weight: T },
+
+  /// Push `registers[register] * weight` (a forward jumper to a neuron computed earlier in this evaluation)
+  Load      { register: u16, weight: T },
+
+  /// Pop `inputs` values, and push the activation function applied to their sum
+  Neuron    { inputs: u16 },
+
+  /// Copy the top of the stack into `registers[register]`
+  Store     { register: u16 },
+
+  /// Multiply the top of the stack by `weight`
+  Weight    { weight: T },
+}
+
+/// A compiled network.
+pub struct Program<T: 'static> {
+  /// The genome, as bytecode
+  pub instructions: &'static [Instruction<T>],
+
+  /// For each element of the recurrent state, the register it is updated from (after evaluation)
+  pub persist:      &'static [u16],
+
+  /// The activation function of every neuron
+  pub activation:   fn(T) -> T,
+}
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T>> Program<T> {
+  /// Run the program.
+  /// - `stack` and `registers` are scratch space, and must be at least as large as the program requires
+  ///   (generated code sizes them exactly).
+  /// - `persistence` is read during evaluation, and only updated at the end.
+  pub fn evaluate(&self, inputs: &[T], outputs: &mut [T], persistence: &mut [T], stack: &mut [T], registers: &mut [T]) {
+    let mut top = 0;
+
+    for instruction in self.instructions {
+      match *instruction {
+        Instruction::Input { index, weight } => {
+          stack[top] = weight * inputs[index as usize];
+          top += 1;
+        },
+        Instruction::Bias { value } => {
+          stack[top] = value;
+          top += 1;
+        },
+        Instruction::Recurrent { state, weight } => {
+          stack[top] = weight * persistence[state as usize];
+          top += 1;
+        },
+        Instruction::Load { register, weight } => {
+          stack[top] = registers[register as usize] * weight;
+          top += 1;
+        },
+        Instruction::Neuron { inputs } => {
+          // sum in the same order as `cge` (oldest value first)
+          let start = top - inputs as usize;
+          let mut sum = stack[start];
+          for &value in &stack[start + 1..top] { sum = sum + value; }
+
+          stack[start] = (self.activation)(sum);
+          top = start + 1;
+        },
+        Instruction::Store { register } => registers[register as usize] = stack[top - 1],
+        Instruction::Weight { weight }  => stack[top - 1] = stack[top - 1] * weight,
+      }
+    }
+
+    // whatever remains on the stack is the output of the network
+    outputs.copy_from_slice(&stack[..top]);
+
+    for (state, &register) in persistence.iter_mut().zip(self.persist) {
+      *state = registers[register as usize];
+    }
+  }
+}
@@ -0,0 +1,13 @@
+//! Helpers used by networks compiled with `#[ensemble(...)]`.
+
+/// `values`, in ascending order (insertion sort: ensembles are small). The order around `NaN`s is unspecified.
+pub fn sorted<T: Copy + PartialOrd, const M: usize>(mut values: [T; M]) -> [T; M] {
+  for index in 1..M {
+    let mut position = index;
+    while position > 0 && values[position] < values[position - 1] {
+      values.swap(position, position - 1);
+      position -= 1;
+    }
+  }
+  values
+}
@@ -0,0 +1,29 @@
+use core::fmt;
+
+/// Returned by the generated `try_evaluate_slice` / `evaluate_iter` / `evaluate_sequence` when the given
+/// inputs or outputs do not match the dimensions of the network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DimensionError {
+  /// Received `found` inputs, but the network has `expected` (`INPUT_COUNT`).
+  /// - `evaluate_iter` stops reading at `INPUT_COUNT + 1`, so `found` is at most that.
+  Inputs  { expected: usize, found: usize },
+
+  /// Received room for `found` outputs, but the network has `expected` (`OUTPUT_COUNT`).
+  Outputs { expected: usize, found: usize },
+
+  /// `evaluate_sequence` received `inputs` input vectors, but room for `outputs` output vectors (it needs one per input).
+  Sequence { inputs: usize, outputs: usize },
+}
+
+impl fmt::Display for DimensionError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      DimensionError::Inputs  { expected, found } => write!(f, "network expects {} inputs, but received {}", expected, found),
+      DimensionError::Outputs { expected, found } => write!(f, "network produces {} outputs, but received room for {}", expected, found),
+      DimensionError::Sequence { inputs, outputs } => write!(f, "sequence has {} input vectors, but room for {} output vectors", inputs, outputs),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DimensionError {}
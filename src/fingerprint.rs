@@ -0,0 +1,41 @@
+//! Helpers for pinning networks by their `FINGERPRINT` (or `TOPOLOGY_HASH`) at compile time.
+//!
+//! ```rust,ignore
+//! // copied from the generated documentation of the network you validated
+//! const VALIDATED: [u8; 32] = const_cge::fingerprint_from_hex("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08");
+//! const _: () = assert!(const_cge::same_fingerprint(&Ocr::FINGERPRINT, &VALIDATED), "the OCR network changed");
+//! ```
+
+/// Compare two fingerprints (usable in `const` contexts, unlike `==` on arrays).
+pub const fn same_fingerprint(a: &[u8; 32], b: &[u8; 32]) -> bool {
+  let mut i = 0;
+  while i < 32 {
+    if a[i] != b[i] { return false; }
+    i += 1;
+  }
+  true
+}
+
+/// Parse a fingerprint from 64 hex digits (as printed in the generated documentation).
+/// - Panics (at compile time, in `const` contexts) if the string is not 64 hex digits.
+pub const fn fingerprint_from_hex(hex: &str) -> [u8; 32] {
+  const fn digit(c: u8) -> u8 {
+    match c {
+      b'0'..=b'9' => c - b'0',
+      b'a'..=b'f' => c - b'a' + 10,
+      b'A'..=b'F' => c - b'A' + 10,
+      _ => panic!("fingerprint contains a character which is not a hex digit"),
+    }
+  }
+
+  let hex = hex.as_bytes();
+  assert!(hex.len() == 64, "fingerprint must be 64 hex digits");
+
+  let mut bytes = [0; 32];
+  let mut i = 0;
+  while i < 32 {
+    bytes[i] = digit(hex[2 * i]) << 4 | digit(hex[2 * i + 1]);
+    i += 1;
+  }
+  bytes
+}
@@ -0,0 +1,50 @@
+//! Post-processing applied to the outputs of networks compiled with `head = ...`.
+//! - Generic over the numeric type, the exponential comes from the selected `activations` backend.
+use core::ops::{Add, Sub, Div};
+
+/// Index of the largest value (the first, if tied). `NaN`s never win, unless every value is `NaN` (then `0`).
+pub fn argmax<T: Copy + PartialOrd, const N: usize>(values: &[T; N]) -> usize {
+  let mut best: Option<usize> = None;
+  for (index, value) in values.iter().enumerate() {
+    // `NaN` is the only value unordered with itself
+    if value.partial_cmp(value).is_none() { continue; }
+
+    match best {
+      Some(best) if *value <= values[best] => {},
+      _ => best = Some(index)
+    }
+  }
+  best.unwrap_or(0)
+}
+
+/// Replace `values` by their softmax (positive, and summing to one), using `exp` from an `activations` backend.
+/// - The largest value is subtracted first, so large outputs cannot overflow `exp`.
+pub fn softmax<T, const N: usize>(values: &mut [T; N], exp: fn(T) -> T)
+where
+  T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Div<Output = T>
+{
+  if N == 0 { return; }
+
+  let max = values[argmax(values)];
+  for value in values.iter_mut() { *value = exp(*value - max); }
+
+  let sum = values[1..].iter().fold(values[0], |sum, &value| sum + value);
+  for value in values.iter_mut() { *value = *value / sum; }
+}
+
+/// Replace `values` by a one-hot encoding of their `argmax` (`one` at the largest value, `zero` everywhere else).
+pub fn one_hot<T: Copy + PartialOrd, const N: usize>(values: &mut [T; N], zero: T, one: T) {
+  if N == 0 { return; }
+
+  let winner = argmax(values);
+  for (index, value) in values.iter_mut().enumerate() {
+    *value = if index == winner { one } else { zero };
+  }
+}
+
+/// Clamp every value into `[lo, hi]` (`NaN`s pass through).
+pub fn clamp<T: Copy + PartialOrd, const N: usize>(values: &mut [T; N], lo: T, hi: T) {
+  for value in values.iter_mut() {
+    if *value < lo { *value = lo; } else if *value > hi { *value = hi; }
+  }
+}
@@ -2,5 +2,15 @@
 #![doc = core::include_str!("../README.md")] // mirror the README verbatim
 pub use const_cge_macro::*;            // make the macros available to users & netcrate authors
 pub mod activations;                   // expose feature-determined `activations` implementations available to generated functions.
+mod error; pub use error::*;           // errors returned by generated functions
+mod state; pub use state::*;           // snapshots of recurrent state
+mod fingerprint; pub use fingerprint::*; // pinning networks by their fingerprint
+mod metadata; pub use metadata::*;     // metadata declared by netcrate authors
+mod stage; pub use stage::*;           // networks as stages of a `pipeline!`
+pub mod head;                          // post-processing used by networks with `head = ...`
+pub mod ensemble;                      // helpers used by networks compiled with `#[ensemble(...)]`
+pub mod unroll;                        // the explicit steps behind the generated `evaluate_unrolled::<K>`
+pub mod compact;                       // the bytecode interpreter shared by networks compiled with `mode = compact`
+#[cfg(feature = "simd")] pub mod simd; // the layer kernel used by networks compiled with `mode = simd`
 
 #[cfg(test)] mod tests;                // property test static evals against dynamic evals
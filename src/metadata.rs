@@ -0,0 +1,30 @@
+//! Metadata declared by netcrate authors (`netcrate!(name = "net.cge", version = "1.0.0", ...)`).
+//! - Query it with `const_cge::metadata!(netcrate::name)`, in `const` if you like (e.g. to check a version before depending on a network).
+
+/// Author-declared metadata of a network exported by a netcrate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Metadata {
+  /// The name the network is exported under, including its namespace (e.g. `"vision::ocr"`)
+  pub name:          &'static str,
+
+  /// The version of the network (not of the netcrate)
+  pub version:       Option<&'static str>,
+
+  /// The license the network is distributed under
+  pub license:       Option<&'static str>,
+
+  /// What the network does (defaults to the description in its CGE file)
+  pub description:   Option<&'static str>,
+
+  /// Number of inputs to the network
+  pub input_count:   usize,
+
+  /// Number of outputs of the network
+  pub output_count:  usize,
+
+  /// A label for every input (`input_count` of them)
+  pub input_labels:  Option<&'static [&'static str]>,
+
+  /// A label for every output (`output_count` of them)
+  pub output_labels: Option<&'static [&'static str]>,
+}
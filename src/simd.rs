@@ -0,0 +1,33 @@
+//! The kernel behind `mode = simd`.
+//! - Generated code computes a whole layer of independent neurons per call, with one neuron per lane.
+//! - Lanes are plain arrays (stable Rust), laid out so that each step is a single elementwise
+//!   multiply-add across every neuron of the layer, which LLVM reliably turns into vector instructions.
+use core::ops::{Add, Mul};
+
+/// Evaluate one layer of neurons.
+/// - `sources[j][lane]` is the `j`th input of neuron `lane`, `weights[j][lane]` is its weight.
+/// - Each neuron's terms are summed in order (`j = 0, 1, ...`), exactly like `cge`.
+/// - Neurons with fewer than `M` inputs are padded with `0.0 * -0.0` terms, adding `-0.0` changes nothing.
+#[inline(always)]
+pub fn layer<T, const K: usize, const M: usize>(
+  sources:    &[[T; K]; M],
+  weights:    &[[T; K]; M],
+  activation: impl Fn(T) -> T
+) -> [T; K]
+where
+  T: Copy + Add<Output = T> + Mul<Output = T>
+{
+  // start from the first term (rather than zero), so the result is bitwise identical to a scalar sum
+  let mut sums = sources[0];
+  for (sum, weight) in sums.iter_mut().zip(&weights[0]) {
+    *sum = *sum * *weight;
+  }
+
+  for (sources, weights) in sources.iter().zip(weights).skip(1) {
+    for ((sum, &source), &weight) in sums.iter_mut().zip(sources).zip(weights) {
+      *sum = *sum + source * weight;
+    }
+  }
+
+  sums.map(activation)
+}
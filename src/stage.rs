@@ -0,0 +1,14 @@
+//! Networks as stages of a `pipeline!`.
+//! - Every compiled network (and every pipeline) implements [`Stage`], so pipelines can chain them
+//!   without caring which are recurrent, or what their numeric type is.
+
+/// A network (or pipeline) evaluated as one stage of a `pipeline!`.
+pub trait Stage: Default {
+  /// The numeric type of the inputs and outputs (`numeric_type = ...`).
+  type Numeric: Copy;
+
+  /// `evaluate`, through `&mut self` whether or not the network is recurrent, and on slices.
+  /// - Panics unless `inputs.len() == INPUT_COUNT` and `outputs.len() == OUTPUT_COUNT`
+  ///   (`pipeline!` checks this at compile time, and the checks vanish once inlined).
+  fn evaluate_stage(&mut self, inputs: &[Self::Numeric], outputs: &mut [Self::Numeric]);
+}
@@ -0,0 +1,124 @@
+//! Snapshots of recurrent state.
+//! - Every recurrent network gets a `<Name>State` alias of [`State`], tagged with the network's fingerprint
+//!   (the first 8 bytes of its `FINGERPRINT`, little endian),
+//!   so a snapshot of one network cannot be restored into another (it is a different type).
+//! - With the `serde` feature, snapshots (de)serialize with their fingerprint embedded, and refuse to deserialize
+//!   for a different network. The format is a fixed-size tuple, which suits `postcard` (e.g. to persist state in flash).
+use core::hash::{Hash, Hasher};
+
+/// A snapshot of the recurrent state of the network with fingerprint tag `FINGERPRINT`.
+/// - `Eq` and `Hash` compare the bits of each element (so `NaN == NaN`, and `0.0 != -0.0`).
+#[derive(Clone, Copy, Debug)]
+pub struct State<T, const SIZE: usize, const FINGERPRINT: u64>(pub(crate) [T; SIZE]);
+
+impl<T, const SIZE: usize, const FINGERPRINT: u64> State<T, SIZE, FINGERPRINT> {
+  /// The fingerprint tag of the network this state belongs to (the first 8 bytes of its `FINGERPRINT`, little endian).
+  pub const FINGERPRINT: u64 = FINGERPRINT;
+
+  /// Wrap raw recurrent state (as returned by `recurrent_state`).
+  pub const fn new(state: [T; SIZE]) -> Self { State(state) }
+
+  /// The raw recurrent state.
+  pub const fn as_array(&self) -> &[T; SIZE] { &self.0 }
+
+  /// Unwrap the raw recurrent state.
+  pub fn into_array(self) -> [T; SIZE] { self.0 }
+}
+
+macro_rules! bitwise_eq_and_hash {
+  ($($numeric:ty),*) => {$(
+    impl<const SIZE: usize, const FINGERPRINT: u64> PartialEq for State<$numeric, SIZE, FINGERPRINT> {
+      fn eq(&self, other: &Self) -> bool {
+        self.0.iter().zip(&other.0).all(|(a, b)| a.to_bits() == b.to_bits())
+      }
+    }
+
+    impl<const SIZE: usize, const FINGERPRINT: u64> Eq for State<$numeric, SIZE, FINGERPRINT> {}
+
+    impl<const SIZE: usize, const FINGERPRINT: u64> Hash for State<$numeric, SIZE, FINGERPRINT> {
+      fn hash<H: Hasher>(&self, state: &mut H) {
+        for value in &self.0 { value.to_bits().hash(state); }
+      }
+    }
+  )*};
+}
+
+bitwise_eq_and_hash!(f32, f64);
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+  use core::{fmt, marker::PhantomData};
+  use serde::{Serialize, Serializer, Deserialize, Deserializer, ser::SerializeTuple, de::{self, Visitor, SeqAccess}};
+  use super::State;
+
+  /// Serialized as `(fingerprint, (state[0], state[1], ...))`, without any length prefixes.
+  impl<T: Serialize, const SIZE: usize, const FINGERPRINT: u64> Serialize for State<T, SIZE, FINGERPRINT> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      struct Elements<'a, T, const SIZE: usize>(&'a [T; SIZE]);
+
+      impl<T: Serialize, const SIZE: usize> Serialize for Elements<'_, T, SIZE> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+          let mut tuple = serializer.serialize_tuple(SIZE)?;
+          for value in self.0 { tuple.serialize_element(value)?; }
+          tuple.end()
+        }
+      }
+
+      let mut tuple = serializer.serialize_tuple(2)?;
+      tuple.serialize_element(&FINGERPRINT)?;
+      tuple.serialize_element(&Elements(&self.0))?;
+      tuple.end()
+    }
+  }
+
+  /// Fails if the embedded fingerprint belongs to a different network.
+  impl<'de, T: Deserialize<'de> + Copy + Default, const SIZE: usize, const FINGERPRINT: u64> Deserialize<'de> for State<T, SIZE, FINGERPRINT> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      struct Elements<T, const SIZE: usize>([T; SIZE]);
+
+      impl<'de, T: Deserialize<'de> + Copy + Default, const SIZE: usize> Deserialize<'de> for Elements<T, SIZE> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+          struct ElementsVisitor<T, const SIZE: usize>(PhantomData<T>);
+
+          impl<'de, T: Deserialize<'de> + Copy + Default, const SIZE: usize> Visitor<'de> for ElementsVisitor<T, SIZE> {
+            type Value = Elements<T, SIZE>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{} recurrent state elements", SIZE) }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+              let mut state = [T::default(); SIZE];
+              for (index, value) in state.iter_mut().enumerate() {
+                *value = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(index, &self))?;
+              }
+              Ok(Elements(state))
+            }
+          }
+
+          deserializer.deserialize_tuple(SIZE, ElementsVisitor(PhantomData))
+        }
+      }
+
+      struct StateVisitor<T, const SIZE: usize, const FINGERPRINT: u64>(PhantomData<T>);
+
+      impl<'de, T: Deserialize<'de> + Copy + Default, const SIZE: usize, const FINGERPRINT: u64> Visitor<'de> for StateVisitor<T, SIZE, FINGERPRINT> {
+        type Value = State<T, SIZE, FINGERPRINT>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "a fingerprint followed by recurrent state") }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+          let fingerprint: u64 = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+          if fingerprint != FINGERPRINT {
+            return Err(de::Error::custom(format_args!(
+              "recurrent state belongs to a different network (fingerprint {:#018x}, expected {:#018x})", fingerprint, FINGERPRINT
+            )));
+          }
+
+          let Elements(state) = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+          Ok(State(state))
+        }
+      }
+
+      deserializer.deserialize_tuple(2, StateVisitor(PhantomData))
+    }
+  }
+}
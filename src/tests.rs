@@ -25,31 +25,1477 @@ mod netcrate_test {
   }
 }
 
+/// Namespaced netcrate exports, and their metadata (see `test_inputs/netcrate_zoo`)
+mod namespaced_netcrates {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[network(netcrate_zoo::plain)]
+  struct Plain;
+
+  #[recurrent(netcrate_zoo::vision::edges, numeric_type = f32)]
+  struct Edges;
+
+  #[network(netcrate_zoo::control::cart_pole)]
+  struct CartPole;
+
+  #[network("./test_inputs/test_network_v1.cge")]
+  struct Local;
+
+  #[network("./test_inputs/test_network_v1.cge", numeric_type = f32)]
+  struct Narrowed;
+
+  const EDGES: Metadata = metadata!(netcrate_zoo::vision::edges);
+
+  // metadata is available before (and without) compiling the network
+  const _: () = assert!(EDGES.input_count == 2 && EDGES.output_count == 1);
+
+  #[test]
+  fn namespaced_networks_are_the_exported_networks() {
+    assert_eq!(Plain::FINGERPRINT, Local::FINGERPRINT);
+    assert_eq!(Edges::TOPOLOGY_HASH, Narrowed::TOPOLOGY_HASH);
+    assert_ne!(CartPole::FINGERPRINT, Plain::FINGERPRINT);
+  }
+
+  #[test]
+  fn declared_metadata() {
+    assert_eq!(EDGES, Metadata {
+      name:          "vision::edges",
+      version:       Some("1.2.0"),
+      license:       Some("MIT"),
+      description:   Some("An example network (taken from the CGE paper with an additional bias gene added)."),
+      input_count:   Edges::INPUT_COUNT,
+      output_count:  Edges::OUTPUT_COUNT,
+      input_labels:  Some(&["brightness", "contrast"]),
+      output_labels: Some(&["edge"]),
+    });
+
+    let cart_pole = metadata!(netcrate_zoo::control::cart_pole);
+    assert_eq!(cart_pole.name, "control::cart_pole");
+    assert_eq!(cart_pole.version, Some("0.3.1"));
+    assert_eq!(cart_pole.license, None);
+    assert_eq!(cart_pole.description, Some("Balances a pole on a cart"));
+    assert_eq!(cart_pole.input_count, CartPole::INPUT_COUNT);
+    assert_eq!(cart_pole.output_labels, None);
+
+    let plain = metadata!(netcrate_zoo::plain);
+    assert_eq!((plain.name, plain.version), ("plain", None));
+  }
+}
+
+/// Every argument is forwarded through netcrates, and overrides the author's defaults
+mod netcrate_arguments {
+  use crate as const_cge;
+  use const_cge::*;
+
+  // `netcrate_zoo::wave` defaults to `numeric_type = f32, mode = lut(...)`
+  #[nonrecurrent(netcrate_zoo::wave)]
+  struct DefaultWave;
+
+  #[nonrecurrent("./test_inputs/lut_wave.cge", numeric_type = f32, mode = lut(resolution = 256, ranges = [-2.0..2.0]))]
+  struct LocalWaveLut;
+
+  #[nonrecurrent(netcrate_zoo::wave, numeric_type = f64, mode = arithmetic)]
+  struct OverriddenWave;
+
+  #[nonrecurrent("./test_inputs/lut_wave.cge")]
+  struct LocalWave;
+
+  #[recurrent(netcrate_zoo::plain, numeric_type = f32, accumulate = f64, fma = true, verify = 50)]
+  struct Precise;
+
+  #[recurrent("./test_inputs/test_network_v1.cge", numeric_type = f32, accumulate = f64, fma = true)]
+  struct LocalPrecise;
+
+  #[test]
+  fn author_defaults_apply() {
+    for i in 0..=100 {
+      let x = -2.5 + i as f32 / 20.0;
+      let (mut from_netcrate, mut local) = ([0.0f32], [0.0f32]);
+      DefaultWave::evaluate(&[x], &mut from_netcrate);
+      LocalWaveLut::evaluate(&[x], &mut local);
+      assert_eq!(from_netcrate[0].to_bits(), local[0].to_bits());
+    }
+  }
+
+  #[test]
+  fn user_arguments_override_defaults() {
+    for i in 0..=100 {
+      let x = -2.5 + i as f64 / 20.0;
+      let (mut from_netcrate, mut local) = ([0.0f64], [0.0f64]);
+      OverriddenWave::evaluate(&[x], &mut from_netcrate);
+      LocalWave::evaluate(&[x], &mut local);
+      assert_eq!(from_netcrate[0].to_bits(), local[0].to_bits());
+    }
+  }
+
+  #[test]
+  fn every_argument_is_forwarded() {
+    let (mut from_netcrate, mut local) = (Precise::default(), LocalPrecise::default());
+    for i in 0..20 {
+      let inputs = [i as f32 * 0.37 - 3.0, 1.0 - i as f32 * 0.11];
+      let (mut a, mut b) = ([0.0f32], [0.0f32]);
+      from_netcrate.evaluate(&inputs, &mut a);
+      local.evaluate(&inputs, &mut b);
+      assert_eq!(a[0].to_bits(), b[0].to_bits());
+    }
+  }
+}
+
+/// Explicit `file = `, `data = ` and `env = ` sources
+mod source_kinds {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[recurrent("./test_inputs/test_network_recurrent.cge")]
+  struct Guessed;
+
+  #[recurrent(file = "./test_inputs/test_network_recurrent.cge")]
+  struct FromFile;
+
+  #[recurrent(data = r#"{"version":"1","network":{"metadata":{"description":null},"activation":"linear","genome":[
+    {"kind":"neuron","id":0,"num_inputs":2,"weight":1.0},
+    {"kind":"recurrentjumper","source_id":1,"weight":3.0},
+    {"kind":"neuron","id":1,"num_inputs":1,"weight":1.0},
+    {"kind":"bias","value":1.0}
+  ],"recurrent_state":null,"extra":null}}"#)]
+  struct FromData;
+
+  // set by `.cargo/config.toml`
+  #[recurrent(env = "CONST_CGE_TEST_MODEL", numeric_type = f32)]
+  struct FromEnvironment;
+
+  #[recurrent("./test_inputs/test_network_recurrent.cge", numeric_type = f32)]
+  struct Guessed32;
+
+  #[test]
+  fn every_source_compiles_the_same_network() {
+    assert_eq!(FromFile::FINGERPRINT, Guessed::FINGERPRINT);
+    assert_eq!(FromData::FINGERPRINT, Guessed::FINGERPRINT);
+    assert_eq!(FromEnvironment::FINGERPRINT, Guessed32::FINGERPRINT);
+  }
+
+  #[test]
+  fn environment_network_evaluates() {
+    let mut network = FromEnvironment::default();
+    for correct in [1.0f32, 4.0] {
+      let mut outputs = [0.0f32];
+      network.evaluate(&[], &mut outputs);
+      assert_eq!(outputs, [correct]);
+    }
+  }
+}
+
+/// Paths are relative to the root of the crate invoking the macro, wherever rustc runs
+/// (`netcrate_zoo` is compiled from the root of this crate, not its own)
+mod crate_root_paths {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[recurrent("./test_inputs/test_network_v1.cge")]
+  struct Local;
+
+  #[recurrent("./test_inputs/fig_5_3_paper.cge")]
+  struct LocalFile;
+
+  #[test]
+  fn paths_resolve_against_the_invoking_crate() {
+    assert_eq!(netcrate_zoo::Resolved::FINGERPRINT, Local::FINGERPRINT);
+    assert_eq!(netcrate_zoo::ResolvedFile::FINGERPRINT, LocalFile::FINGERPRINT);
+
+    let (mut resolved, mut local) = (netcrate_zoo::Resolved::default(), Local::default());
+    for inputs in [[1.0, 0.5], [-0.5, 2.0], [0.0, 0.0]] {
+      let (mut a, mut b) = ([0.0], [0.0]);
+      resolved.evaluate(&inputs, &mut a);
+      local.evaluate(&inputs, &mut b);
+      assert_eq!(a, b);
+    }
+  }
+}
+
+/// `inputs`, `outputs`, `max_state` and `activation` assertions
+mod interface_assertions {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[recurrent("./test_inputs/test_network_v1.cge", inputs = 2, outputs = 1, max_state = 2, activation = linear)]
+  struct Asserted;
+
+  #[nonrecurrent("./test_inputs/lut_surface.cge", inputs = 2, max_state = 0)]
+  struct Stateless;
+
+  // forwarded through netcrates too
+  #[network(netcrate_zoo::vision::edges, inputs = 2, outputs = 1, activation = linear)]
+  struct FromNetcrate;
+
+  #[test]
+  fn matching_interfaces_compile() {
+    assert_eq!((Asserted::INPUT_COUNT, Asserted::OUTPUT_COUNT), (2, 1));
+    assert_eq!(Asserted::PERSISTENT_SIZE, 1); // (`max_state` is an upper bound)
+    assert_eq!(Stateless::INPUT_COUNT, 2);
+    assert_eq!(FromNetcrate::FINGERPRINT, Asserted::FINGERPRINT);
+  }
+
+  #[test] // does indeed fail (at the attribute: "`inputs = 12` was asserted, but the network has 2 inputs")
+  fn mismatched_interfaces() {
+    // #[network("./test_inputs/test_network_v1.cge", inputs = 12)]
+    // struct Changed;
+  }
+}
+
+/// `expect` and `expect_file` golden vectors (each generates a `__const_cge_expect_*` test)
+mod golden_vectors {
+  use crate as const_cge;
+  use const_cge::*;
+
+  // consecutive steps from the initial state (the network is recurrent)
+  #[recurrent("./test_inputs/test_network_v1.cge", expect = [([1.0, 0.5], [0.7524]), ([0.0, -1.0], [-0.133104]), ([2.0, 2.0], [1.66267584])])]
+  struct Inline;
+
+  #[recurrent("./test_inputs/test_network_v1.cge", numeric_type = f32, expect_file = "./test_inputs/test_network_v1_expect.json")]
+  struct FromFile;
+
+  #[nonrecurrent("./test_inputs/lut_wave.cge", expect = [([0.0], [-0.43696891867738635])])]
+  struct Stateless;
+
+  // `netcrate_zoo` ships golden vectors for `vision::edges` (as a default)
+  #[network(netcrate_zoo::vision::edges, mode = compact)]
+  struct FromNetcrate;
+
+  #[test] // does indeed fail (at the attribute: "Network fails `expect` vector 0 (inputs [1.0, 0.5]): output 0 is 0.7524, but 0.75 was expected.")
+  fn wrong_expectations() {
+    // #[recurrent("./test_inputs/test_network_v1.cge", expect = [([1.0, 0.5], [0.75])])]
+    // struct Wrong;
+  }
+}
+
+/// `input_scale` / `input_offset`, folded into the input weights
+mod input_normalization {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[recurrent("./test_inputs/test_network_v1.cge")]
+  struct Raw;
+
+  #[recurrent("./test_inputs/test_network_v1.cge", input_scale = [2.0, 0.5], input_offset = [-1.0, 0.25], verify = 100)]
+  struct Folded;
+
+  // the same normalization, from the `extra` section of the CGE file
+  #[recurrent("./test_inputs/normalized_extra.cge", verify = 100)]
+  struct FromExtra;
+
+  // attribute arguments take precedence over the CGE file
+  #[recurrent("./test_inputs/normalized_extra.cge", input_scale = [1.0, 1.0], input_offset = [0.0, 0.0])]
+  struct Overridden;
+
+  #[nonrecurrent("./test_inputs/lut_wave.cge", input_scale = [4.0])]
+  struct ScaledOnly;
+
+  #[nonrecurrent("./test_inputs/lut_wave.cge")]
+  struct Wave;
+
+  const INPUTS: [[f64; 2]; 4] = [[1.0, 0.5], [0.0, -1.0], [2.0, 2.0], [-0.25, 0.75]];
+
+  #[test]
+  fn folding_matches_normalizing_by_hand() {
+    let (mut raw, mut folded) = (Raw::default(), Folded::default());
+    for inputs in INPUTS {
+      let normalized = [inputs[0] * 2.0 - 1.0, inputs[1] * 0.5 + 0.25];
+      let (mut expected, mut actual) = ([0.0], [0.0]);
+      raw.evaluate(&normalized, &mut expected);
+      folded.evaluate(&inputs, &mut actual);
+      assert!((expected[0] - actual[0]).abs() < 1e-12, "{} vs {}", expected[0], actual[0]);
+    }
+  }
+
+  #[test]
+  fn extra_section_is_folded() {
+    assert_eq!(FromExtra::FINGERPRINT, Folded::FINGERPRINT);
+    assert_eq!(Overridden::FINGERPRINT, Raw::FINGERPRINT);
+  }
+
+  #[test]
+  fn scale_without_offset() {
+    // (no biases needed, so the structure is unchanged)
+    assert_eq!(ScaledOnly::TOPOLOGY_HASH, Wave::TOPOLOGY_HASH);
+    assert_eq!(Folded::BIAS_COUNT, Raw::BIAS_COUNT + 5);
+
+    for x in [-1.0, -0.3, 0.0, 0.6] {
+      let (mut expected, mut actual) = ([0.0], [0.0]);
+      Wave::evaluate(&[x * 4.0], &mut expected);
+      ScaledOnly::evaluate(&[x], &mut actual);
+      assert!((expected[0] - actual[0]).abs() < 1e-12, "{} vs {}", expected[0], actual[0]);
+    }
+  }
+}
+
+/// `head = softmax | argmax | clamp(lo, hi)`, and `labels` (with `classify`)
+mod output_heads {
+  extern crate std;
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge")]
+  struct Raw;
+
+  // generated tests check `evaluate_raw` (golden vectors and the `cge` runtime know nothing of the head)
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge", head = softmax, verify = 100)]
+  struct Probabilities;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge", numeric_type = f32, head = softmax)]
+  struct Probabilities32;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge", head = argmax, labels = ["idle", "walk", "run_fast"])]
+  pub struct Gait;
+
+  #[recurrent("./test_inputs/test_network_v1.cge", head = clamp(-0.5, 0.5), expect_file = "./test_inputs/test_network_v1_expect.json", verify = 100)]
+  struct Clamped;
+
+  #[recurrent("./test_inputs/test_network_v1.cge")]
+  struct Unclamped;
+
+  const INPUTS: [[f64; 2]; 4] = [[1.0, 0.5], [0.0, -1.0], [2.0, 2.0], [-0.25, 0.75]];
+
+  #[test]
+  fn softmax() {
+    for inputs in INPUTS {
+      let (mut raw, mut probabilities) = ([0.0; 3], [0.0; 3]);
+      Raw::evaluate(&inputs, &mut raw);
+      Probabilities::evaluate(&inputs, &mut probabilities);
+
+      let sum: f64 = raw.iter().map(|x| x.exp()).sum();
+      for (&raw, &probability) in raw.iter().zip(&probabilities) {
+        assert!((raw.exp() / sum - probability).abs() < 1e-12, "{} vs {}", raw.exp() / sum, probability);
+      }
+
+      let mut before_head = [0.0; 3];
+      Probabilities::evaluate_raw(&inputs, &mut before_head);
+      assert_eq!(before_head, raw);
+
+      let mut narrow = [0.0; 3];
+      Probabilities32::evaluate(&inputs.map(|x| x as f32), &mut narrow);
+      assert!((narrow.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+    }
+  }
+
+  #[test]
+  fn argmax_and_labels() {
+    for inputs in INPUTS {
+      let (mut raw, mut one_hot) = ([0.0; 3], [0.0; 3]);
+      Raw::evaluate(&inputs, &mut raw);
+      Gait::evaluate(&inputs, &mut one_hot);
+
+      let winner = head::argmax(&raw);
+      assert_eq!(one_hot.iter().filter(|&&x| x == 1.0).count(), 1);
+      assert_eq!(one_hot[winner], 1.0);
+      assert_eq!(Gait::classify(&inputs).index(), winner);
+    }
+
+    assert_eq!(GaitLabel::ALL, [GaitLabel::Idle, GaitLabel::Walk, GaitLabel::RunFast]);
+    assert_eq!(GaitLabel::RunFast.as_str(), "run_fast");
+    assert_eq!(GaitLabel::from_index(1), Some(GaitLabel::Walk));
+    assert_eq!(GaitLabel::from_index(3), None);
+    assert_eq!(std::string::ToString::to_string(&GaitLabel::Idle), "idle");
+  }
+
+  #[test]
+  fn clamp() {
+    let (mut clamped, mut unclamped) = (Clamped::default(), Unclamped::default());
+    for inputs in INPUTS {
+      let (mut expected, mut actual) = ([0.0], [0.0]);
+      unclamped.evaluate(&inputs, &mut expected);
+      clamped.evaluate(&inputs, &mut actual);
+      assert_eq!(actual[0], expected[0].clamp(-0.5, 0.5));
+    }
+  }
+
+  #[test]
+  fn head_helpers() {
+    assert_eq!(head::argmax(&[f64::NAN, 1.0, 3.0, 3.0]), 2);
+    assert_eq!(head::argmax(&[f64::NAN, f64::NAN]), 0);
+
+    let mut values = [1000.0, 1000.0];
+    head::softmax(&mut values, activations::f64::exp);
+    assert_eq!(values, [0.5, 0.5]);
+  }
+}
+
+/// `<Name>Inputs` / `<Name>Outputs` and `evaluate_named`, from `input_names` / `output_names` or the CGE file
+mod named_inputs_and_outputs {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge", numeric_type = f32)]
+  struct Positional;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge", numeric_type = f32, input_names = ["gyro_x", "Gyro Y"], output_names = ["left_motor", "right_motor", "tail"])]
+  pub struct Robot;
+
+  // the same names, from the `extra` section of the CGE file
+  #[nonrecurrent("./test_inputs/named_extra.cge", numeric_type = f32)]
+  pub struct FromExtra;
+
+  // only the outputs are named (inputs stay an array)
+  #[recurrent("./test_inputs/test_network_v1.cge", output_names = ["gate"])]
+  pub struct Gate;
+
+  #[recurrent("./test_inputs/test_network_v1.cge")]
+  struct Ungated;
+
+  // the names a netcrate author declared (`netcrate!(..., input_names = [...], output_names = [...])`)
+  #[recurrent(netcrate_zoo::vision::edges)]
+  pub struct Edges;
+
+  #[test]
+  fn evaluate_named_matches_evaluate() {
+    for inputs in [[1.0, 0.5], [0.0, -1.0], [-0.25, 0.75]] {
+      let mut expected = [0.0; 3];
+      Positional::evaluate(&inputs, &mut expected);
+
+      let outputs = Robot::evaluate_named(&RobotInputs { gyro_x: inputs[0], gyro_y: inputs[1] });
+      assert_eq!([outputs.left_motor, outputs.right_motor, outputs.tail], expected);
+      assert_eq!(<[f32; 3]>::from(outputs), expected);
+
+      let outputs = FromExtra::evaluate_named(&inputs.into());
+      assert_eq!(<[f32; 3]>::from(outputs), expected);
+    }
+  }
+
+  #[test]
+  fn one_side_named() {
+    let (mut gate, mut ungated) = (Gate::default(), Ungated::default());
+    for inputs in [[1.0, 0.5], [0.0, -1.0], [-0.25, 0.75]] {
+      let mut expected = [0.0];
+      ungated.evaluate(&inputs, &mut expected);
+      assert_eq!(gate.evaluate_named(&inputs), GateOutputs { gate: expected[0] });
+    }
+  }
+
+  #[test]
+  fn netcrate_names() {
+    let (mut edges, mut ungated) = (Edges::default(), Ungated::default());
+    for inputs in [[1.0, 0.5], [0.0, -1.0], [-0.25, 0.75]] {
+      let mut expected = [0.0];
+      ungated.evaluate(&inputs, &mut expected);
+      assert_eq!(edges.evaluate_named(&EdgesInputs { brightness: inputs[0], contrast: inputs[1] }), EdgesOutputs { edge: expected[0] });
+    }
+  }
+
+  #[test]
+  fn layout_is_the_array() {
+    assert_eq!(core::mem::size_of::<RobotInputs>(), core::mem::size_of::<[f32; 2]>());
+    assert_eq!(core::mem::align_of::<RobotOutputs>(), core::mem::align_of::<[f32; 3]>());
+    assert_eq!(RobotInputs::from([1.0, 2.0]), RobotInputs { gyro_x: 1.0, gyro_y: 2.0 });
+  }
+}
+
+/// `pipeline!`, against chaining the same networks by hand
+mod pipelines {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge")]
+  pub struct Perception;
+
+  #[recurrent("./test_inputs/test_network_v1.cge")]
+  pub struct Planner;
+
+  #[nonrecurrent("./test_inputs/lut_wave.cge")]
+  pub struct Reflex;
+
+  // 2 => 3 => (2 + 1) => 2 => 1
+  const_cge::pipeline!(pub Robot = Perception => (Planner, Reflex) => Planner);
+
+  // pipelines are stages too
+  const_cge::pipeline!(Nested = Robot => Reflex => Reflex);
+
+  const INPUTS: [[f64; 2]; 4] = [[1.0, 0.5], [0.0, -1.0], [2.0, 2.0], [-0.25, 0.75]];
+
+  /// The same chain, by hand
+  fn by_hand(planner: &mut Planner, motor: &mut Planner, inputs: &[f64; 2]) -> [f64; 1] {
+    let mut perception = [0.0; 3];
+    Perception::evaluate(inputs, &mut perception);
+
+    let (mut plan, mut reflex) = ([0.0; 1], [0.0; 1]);
+    planner.evaluate(&[perception[0], perception[1]], &mut plan);
+    Reflex::evaluate(&[perception[2]], &mut reflex);
+
+    let mut outputs = [0.0; 1];
+    motor.evaluate(&[plan[0], reflex[0]], &mut outputs);
+    outputs
+  }
+
+  #[test]
+  fn pipeline_matches_chaining_by_hand() {
+    assert_eq!((Robot::INPUT_COUNT, Robot::OUTPUT_COUNT), (2, 1));
+
+    let mut robot = Robot::default();
+    let (mut planner, mut motor) = (Planner::default(), Planner::default());
+
+    // (recurrent stages keep their state between evaluations)
+    for inputs in INPUTS.iter().chain(&INPUTS) {
+      let mut outputs = [0.0; 1];
+      robot.evaluate(inputs, &mut outputs);
+      assert_eq!(outputs, by_hand(&mut planner, &mut motor, inputs));
+    }
+
+    // every stage is owned by the pipeline (repeated networks are numbered)
+    assert_eq!(robot.planner.recurrent_state(), planner.recurrent_state());
+    assert_eq!(robot.planner_2.recurrent_state(), motor.recurrent_state());
+
+    robot.reset();
+    assert_eq!(robot.planner.recurrent_state(), Planner::default().recurrent_state());
+  }
+
+  #[test]
+  fn nested_pipelines() {
+    let (mut nested, mut robot) = (Nested::default(), Robot::default());
+    for inputs in INPUTS {
+      let (mut expected, mut actual) = ([0.0], [0.0]);
+      robot.evaluate(&inputs, &mut expected);
+      Reflex::evaluate(&expected.clone(), &mut expected);
+      Reflex::evaluate(&expected.clone(), &mut expected);
+
+      nested.evaluate(&inputs, &mut actual);
+      assert_eq!(actual, expected);
+    }
+  }
+
+  #[nonrecurrent("./test_inputs/lut_wave.cge")]
+  pub struct Loop;
+
+  // `Loop` would be the field `loop`, a keyword
+  const_cge::pipeline!(Looped = Reflex => Loop);
+
+  #[test]
+  fn keyword_stage_names() {
+    let mut looped = Looped::default();
+    let (mut expected, mut actual) = ([0.0], [0.0]);
+    Reflex::evaluate(&[0.5], &mut expected);
+    Loop::evaluate(&expected.clone(), &mut expected);
+
+    looped.evaluate(&[0.5], &mut actual);
+    assert_eq!(actual, expected);
+
+    let Looped { reflex: _, loop_: _ } = looped;
+  }
+}
+
+/// `#[ensemble(...)]`, against its members compiled separately
+mod ensembles {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[recurrent("./test_inputs/test_network_v1.cge")]
+  struct A;
+
+  #[recurrent("./test_inputs/test_network_v1_reweighted.cge")]
+  struct B;
+
+  #[recurrent("./test_inputs/with_extra_data_v1.cge")]
+  struct C;
+
+  #[recurrent("./test_inputs/fig_5_3_paper.cge")]
+  struct D;
+
+  #[ensemble("./test_inputs/test_network_v1.cge", "./test_inputs/test_network_v1_reweighted.cge", "./test_inputs/with_extra_data_v1.cge", combine = mean, verify = 100)]
+  struct Mean;
+
+  #[ensemble("./test_inputs/test_network_v1_reweighted.cge", "./test_inputs/test_network_v1.cge", "./test_inputs/with_extra_data_v1.cge", combine = mean)]
+  struct Swapped;
+
+  #[ensemble("./test_inputs/test_network_v1.cge", "./test_inputs/test_network_v1_reweighted.cge", "./test_inputs/with_extra_data_v1.cge", combine = median)]
+  struct MedianOfThree;
+
+  #[ensemble(
+    "./test_inputs/test_network_v1.cge",
+    "./test_inputs/test_network_v1_reweighted.cge",
+    "./test_inputs/with_extra_data_v1.cge",
+    "./test_inputs/fig_5_3_paper.cge",
+    combine = median,
+    verify = 100,
+  )]
+  struct Median;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge", numeric_type = f32)]
+  struct Classifier;
+
+  #[nonrecurrent("./test_inputs/vote_member.cge", numeric_type = f32)]
+  struct Identity;
+
+  #[ensemble("./test_inputs/test_network_multi_output.cge", "./test_inputs/named_extra.cge", "./test_inputs/vote_member.cge", combine = vote, numeric_type = f32, verify = 100)]
+  struct Vote;
+
+  #[ensemble("./test_inputs/test_network_v1.cge", "./test_inputs/with_extra_data_v1.cge", "./test_inputs/fig_5_3_paper.cge", combine = vote, verify = 100)]
+  struct RecurrentVote;
+
+  const INPUTS: [[f64; 2]; 4] = [[1.0, 0.5], [0.0, -1.0], [2.0, 2.0], [-0.25, 0.75]];
+
+  #[test]
+  fn mean() {
+    assert_eq!(Mean::PERSISTENT_SIZE, A::PERSISTENT_SIZE + B::PERSISTENT_SIZE + C::PERSISTENT_SIZE);
+
+    let (mut mean, mut a, mut b, mut c) = (Mean::default(), A::default(), B::default(), C::default());
+    for inputs in INPUTS.iter().chain(&INPUTS) {
+      let (mut x, mut y, mut z, mut actual) = ([0.0], [0.0], [0.0], [0.0]);
+      a.evaluate(inputs, &mut x);
+      b.evaluate(inputs, &mut y);
+      c.evaluate(inputs, &mut z);
+      mean.evaluate(inputs, &mut actual);
+
+      let expected = (x[0] + y[0] + z[0]) / 3.0;
+      assert!((expected - actual[0]).abs() < 1e-12, "{} vs {}", expected, actual[0]);
+    }
+
+    // every member's recurrent state, back to back
+    let state = [a.recurrent_state().as_slice(), b.recurrent_state(), c.recurrent_state()].concat();
+    assert_eq!(mean.recurrent_state().as_slice(), state.as_slice());
+
+    mean.reset();
+    assert_eq!(mean.recurrent_state(), Mean::default().recurrent_state());
+  }
+
+  #[test]
+  fn median() {
+    let (mut median, mut a, mut b, mut c, mut d) = (Median::default(), A::default(), B::default(), C::default(), D::default());
+    for inputs in INPUTS.iter().chain(&INPUTS) {
+      let mut members = [[0.0]; 4];
+      a.evaluate(inputs, &mut members[0]);
+      b.evaluate(inputs, &mut members[1]);
+      c.evaluate(inputs, &mut members[2]);
+      d.evaluate(inputs, &mut members[3]);
+
+      let mut sorted = members.map(|m| m[0]);
+      sorted.sort_by(|x, y| x.partial_cmp(y).unwrap());
+
+      let mut actual = [0.0];
+      median.evaluate(inputs, &mut actual);
+      assert_eq!(actual[0], (sorted[1] + sorted[2]) * 0.5);
+    }
+  }
+
+  #[test]
+  fn vote() {
+    for inputs in INPUTS.map(|i| i.map(|x| x as f32)) {
+      let (mut classifier, mut identity) = ([0.0; 3], [0.0; 3]);
+      Classifier::evaluate(&inputs, &mut classifier);
+      Identity::evaluate(&inputs, &mut identity);
+
+      let mut expected = [0.0; 3];
+      expected[head::argmax(&classifier)] += 2.0 / 3.0;
+      expected[head::argmax(&identity)] += 1.0 / 3.0;
+
+      let mut actual = [0.0; 3];
+      Vote::evaluate(&inputs, &mut actual);
+      for (expected, actual) in expected.iter().zip(&actual) {
+        assert!((expected - actual).abs() < 1e-6, "{:?} vs {:?}", expected, actual);
+      }
+
+      let mut members = [[0.0; 3]; 3];
+      Vote::evaluate_members(&inputs, &mut members);
+      assert_eq!(members, [classifier, classifier, identity]);
+    }
+  }
+
+  #[test]
+  fn snapshots() {
+    let mut mean = Mean::default();
+    let mut outputs = [0.0];
+    mean.evaluate(&INPUTS[0], &mut outputs);
+
+    let snapshot: MeanState = mean.snapshot();
+    let mut expected = [0.0];
+    mean.evaluate(&INPUTS[1], &mut expected);
+
+    mean.restore(&snapshot);
+    let mut actual = [0.0];
+    mean.evaluate(&INPUTS[1], &mut actual);
+    assert_eq!(expected, actual);
+
+    mean.set_recurrent_state(snapshot.as_array());
+    assert_eq!(mean.recurrent_state(), snapshot.as_array());
+  }
+
+  #[test]
+  fn fingerprints() {
+    // members in another order, or combined another way, are another ensemble
+    assert_ne!(Mean::FINGERPRINT, Swapped::FINGERPRINT);
+    assert_ne!(Mean::FINGERPRINT, MedianOfThree::FINGERPRINT);
+
+    // `A` and `B` only differ in their weights
+    assert_eq!(Mean::TOPOLOGY_HASH, Swapped::TOPOLOGY_HASH);
+    assert_ne!(Mean::TOPOLOGY_HASH, MedianOfThree::TOPOLOGY_HASH);
+  }
+
+  #[test]
+  fn banks_match_separate_instances() {
+    let lanes = |step: usize| core::array::from_fn(|i| INPUTS[(step + i) % INPUTS.len()]);
+
+    let (mut means, mut mean_bank) = ([Mean::default(); 3], MeanBank::<3>::default());
+    let (mut medians, mut median_bank) = ([Median::default(); 3], MedianBank::<3>::default());
+    let (mut votes, mut vote_bank) = ([RecurrentVote::default(); 3], RecurrentVoteBank::<3>::default());
+
+    for step in 0..6 {
+      let inputs: [[f64; 2]; 3] = lanes(step);
+      let (mut expected, mut actual) = ([[0.0]; 3], [[0.0]; 3]);
+
+      means.iter_mut().zip(&inputs).zip(&mut expected).for_each(|((mean, inputs), outputs)| mean.evaluate(inputs, outputs));
+      mean_bank.evaluate_all(&inputs, &mut actual);
+      assert_eq!(expected, actual);
+
+      medians.iter_mut().zip(&inputs).zip(&mut expected).for_each(|((median, inputs), outputs)| median.evaluate(inputs, outputs));
+      median_bank.evaluate_all(&inputs, &mut actual);
+      assert_eq!(expected, actual);
+
+      votes.iter_mut().zip(&inputs).zip(&mut expected).for_each(|((vote, inputs), outputs)| vote.evaluate(inputs, outputs));
+      vote_bank.evaluate_all(&inputs, &mut actual);
+      assert_eq!(expected, actual);
+    }
+
+    for (instance, mean) in means.iter().enumerate() {
+      assert_eq!(&mean_bank.instance_state(instance), mean.recurrent_state());
+    }
+  }
+
+  #[test]
+  fn sorted() {
+    assert_eq!(ensemble::sorted([3.0, -1.0, 2.0, 0.5]), [-1.0, 0.5, 2.0, 3.0]);
+  }
+}
+
 /// Custom test to check that basic recurrency is functioning
 mod custom_recurrency_tests {
   use crate as const_cge;
   use const_cge::*;
 
-  /// Test that a case of true recurrence is handled correctly
+  /// Test that a case of true recurrence is handled correctly
+  #[test]
+  fn recurrent_previous_value() {
+    // statically create network (enforce recurrency, that is central to what we're testing!)
+    #[recurrent("./test_inputs/test_network_recurrent.cge", numeric_type = f64)]
+    struct TestNet;
+    let mut net = TestNet::default();
+
+    for (input, correct) in [([], [1.0]), ([], [4.0])] {
+      let mut static_outputs = [0.0; 1];
+      net.evaluate(&input, &mut static_outputs);
+      assert_eq!(static_outputs, correct);
+    }
+  }
+}
+
+/// Check the structural statistics emitted as associated constants
+mod structural_statistics {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[network("./test_inputs/test_network_v1.cge")]
+  struct TestNet;
+
+  // the constants must be usable for budgeting at compile time
+  const _: () = assert!(TestNet::MUL_COUNT + TestNet::ADD_COUNT <= 21);
+
+  /// Counted by hand from the genome (the forward jumper re-evaluates the subnetwork of neuron 3).
+  #[test]
+  fn test_network_v1() {
+    assert_eq!(TestNet::NEURON_COUNT,         4);
+    assert_eq!(TestNet::CONNECTION_COUNT,     11);
+    assert_eq!(TestNet::BIAS_COUNT,           1);
+    assert_eq!(TestNet::FORWARD_JUMPER_COUNT, 1);
+    assert_eq!(TestNet::DEPTH,                3);
+    assert_eq!(TestNet::MUL_COUNT,            13);
+    assert_eq!(TestNet::ADD_COUNT,            8);
+    assert_eq!(TestNet::ACTIVATION_CALLS,     5);
+  }
+}
+
+/// Check that `verify = N` generates passing tests against the `cge` runtime
+mod verify_attribute {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[recurrent("./test_inputs/test_network_v1.cge", verify = 500)]
+  struct Recurrent64;
+
+  #[recurrent("./test_inputs/with_extra_data_v1.cge", numeric_type = f32, verify = 500)]
+  struct Recurrent32;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge", numeric_type = f32, verify = 500)]
+  struct Stateless32;
+
+  mod netcrate_example {
+    use crate as const_cge;
+    const_cge::netcrate!(verified_testnet = "./test_inputs/test_network_v1.cge");
+  }
+
+  #[network(verified_testnet, verify = 100)]
+  struct FromNetcrate;
+}
+
+/// `FINGERPRINT` and `TOPOLOGY_HASH`
+mod fingerprints {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[network("./test_inputs/test_network_v1.cge")]
+  struct Original;
+
+  /// Same genome, compiled differently
+  #[network("./test_inputs/test_network_v1.cge", mode = compact, fma = false)]
+  struct Recompiled;
+
+  #[network("./test_inputs/test_network_v1.cge", numeric_type = f32)]
+  struct Narrowed;
+
+  #[network("./test_inputs/test_network_v1_reweighted.cge")]
+  struct Reweighted;
+
+  #[network("./test_inputs/fig_5_3_paper.cge")]
+  struct Restructured;
+
+  // the fingerprint must never change for the same network (users pin it)
+  const PINNED: [u8; 32] = fingerprint_from_hex("9abb1ac3061cd65383f3bb91548f22701e1197dc57b9eaa16d7f55bb22fa5f06");
+  const _: () = assert!(same_fingerprint(&Original::FINGERPRINT, &PINNED));
+
+  #[test]
+  fn fingerprint_identifies_the_network() {
+    assert_eq!(Original::FINGERPRINT, Recompiled::FINGERPRINT);
+    assert_ne!(Original::FINGERPRINT, Narrowed::FINGERPRINT);
+    assert_ne!(Original::FINGERPRINT, Reweighted::FINGERPRINT);
+    assert_ne!(Original::FINGERPRINT, Restructured::FINGERPRINT);
+  }
+
+  #[test]
+  fn topology_hash_ignores_weights() {
+    assert_eq!(Original::TOPOLOGY_HASH, Reweighted::TOPOLOGY_HASH);
+    assert_eq!(Original::TOPOLOGY_HASH, Recompiled::TOPOLOGY_HASH);
+    assert_ne!(Original::TOPOLOGY_HASH, Narrowed::TOPOLOGY_HASH);
+    assert_ne!(Original::TOPOLOGY_HASH, Restructured::TOPOLOGY_HASH);
+    assert_ne!(Original::TOPOLOGY_HASH, Original::FINGERPRINT);
+  }
+
+  #[test]
+  fn snapshots_are_tagged_by_fingerprint() {
+    let tag = u64::from_le_bytes([
+      Original::FINGERPRINT[0], Original::FINGERPRINT[1], Original::FINGERPRINT[2], Original::FINGERPRINT[3],
+      Original::FINGERPRINT[4], Original::FINGERPRINT[5], Original::FINGERPRINT[6], Original::FINGERPRINT[7],
+    ]);
+    assert_eq!(OriginalState::FINGERPRINT, tag);
+  }
+
+  #[test]
+  fn hex_round_trip() {
+    assert!(same_fingerprint(&fingerprint_from_hex(&"0aF9".repeat(16)), &[0x0a, 0xf9].repeat(16).try_into().unwrap()));
+    assert!(!same_fingerprint(&Original::FINGERPRINT, &Reweighted::FINGERPRINT));
+  }
+}
+
+/// `reset`, `snapshot` and `restore` of recurrent state
+mod state_snapshots {
+  extern crate std;
+  use crate as const_cge;
+  use const_cge::*;
+  use std::collections::HashSet;
+
+  #[recurrent("./test_inputs/test_network_v1.cge")]
+  struct Controller;
+
+  /// The same recurrent connections (so the same shape of state), but without the bias
+  #[recurrent("./test_inputs/fig_5_3_paper.cge")]
+  struct OtherController;
+
+  const _: () = assert!(Controller::PERSISTENT_SIZE == OtherController::PERSISTENT_SIZE);
+
+  fn run(network: &mut Controller, steps: usize) -> [f64; 1] {
+    let mut outputs = [0.0];
+    for step in 0..steps { network.evaluate(&[step as f64 * 0.1, 0.5], &mut outputs); }
+    outputs
+  }
+
+  #[test]
+  fn snapshot_and_restore() {
+    let mut network = Controller::default();
+    run(&mut network, 5);
+    let snapshot: ControllerState = network.snapshot();
+    let continued = run(&mut network, 3);
+
+    // restoring rewinds to the snapshot
+    network.restore(&snapshot);
+    assert_eq!(network.snapshot(), snapshot);
+    assert_eq!(run(&mut network, 3), continued);
+
+    // resetting is the same as starting fresh
+    network.reset();
+    assert_eq!(network.recurrent_state(), Controller::default().recurrent_state());
+    assert_eq!(snapshot.as_array(), &snapshot.into_array());
+  }
+
+  #[test]
+  fn states_are_eq_and_hash() {
+    let mut network = Controller::default();
+    let mut seen = HashSet::new();
+    seen.insert(network.snapshot());
+    run(&mut network, 1);
+    seen.insert(network.snapshot());
+    network.reset();
+    seen.insert(network.snapshot());
+    assert_eq!(seen.len(), 2);
+
+    // compared bitwise
+    assert_ne!(ControllerState::new([0.0; Controller::PERSISTENT_SIZE]), ControllerState::new([-0.0; Controller::PERSISTENT_SIZE]));
+    assert_eq!(ControllerState::new([f64::NAN; Controller::PERSISTENT_SIZE]), ControllerState::new([f64::NAN; Controller::PERSISTENT_SIZE]));
+  }
+
+  #[test]
+  fn fingerprints_differ_between_networks() {
+    assert_ne!(ControllerState::FINGERPRINT, OtherControllerState::FINGERPRINT);
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn serde_round_trip_checks_fingerprint() {
+    let mut network = Controller::default();
+    run(&mut network, 4);
+    let snapshot = network.snapshot();
+
+    let serialized = serde_json::to_string(&snapshot).unwrap();
+    let deserialized: ControllerState = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, snapshot);
+
+    // same shape of state, but a different network
+    let error = serde_json::from_str::<OtherControllerState>(&serialized).unwrap_err();
+    assert!(std::string::ToString::to_string(&error).contains("different network"), "{}", error);
+  }
+}
+
+/// `<Name>Bank<N>` must behave exactly like `N` separate networks
+mod recurrent_bank {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[recurrent("./test_inputs/fig_5_3_paper_second_output.cge")]
+  struct Controller;
+
+  #[recurrent("./test_inputs/with_extra_data_v1.cge", numeric_type = f32, mode = compact)]
+  struct CompactController;
+
+  #[recurrent("./test_inputs/fig_5_3_paper_second_output.cge", numeric_type = f32, accumulate = f64, fma = true, head = softmax)]
+  struct PreciseController;
+
+  #[test]
+  fn matches_separate_instances() {
+    let mut bank = ControllerBank::<5>::default();
+    let mut instances = [Controller::default(); 5];
+
+    for step in 0..32 {
+      let inputs: [[f64; Controller::INPUT_COUNT]; 5] = core::array::from_fn(|agent| [(step * agent) as f64 * 0.01, agent as f64 * 0.3 - 0.5]);
+      let mut outputs = [[0.0; Controller::OUTPUT_COUNT]; 5];
+      bank.evaluate_all(&inputs, &mut outputs);
+
+      for (agent, instance) in instances.iter_mut().enumerate() {
+        let mut expected = [0.0; Controller::OUTPUT_COUNT];
+        instance.evaluate(&inputs[agent], &mut expected);
+        assert_eq!(outputs[agent], expected);
+        assert_eq!(&bank.instance_state(agent), instance.recurrent_state());
+      }
+    }
+  }
+
+  #[test]
+  fn instances_are_independent() {
+    let mut bank = CompactControllerBank::<2>::default();
+    let state = [0.5; CompactController::PERSISTENT_SIZE];
+    bank.set_instance_state(1, &state);
+
+    // only the second instance remembers anything
+    let mut outputs = [[0.0; CompactController::OUTPUT_COUNT]; 2];
+    bank.evaluate_all(&[[0.0; CompactController::INPUT_COUNT]; 2], &mut outputs);
+
+    let mut fresh = [0.0; CompactController::OUTPUT_COUNT];
+    CompactController::default().evaluate(&[0.0; CompactController::INPUT_COUNT], &mut fresh);
+    let mut remembering = [0.0; CompactController::OUTPUT_COUNT];
+    CompactController::with_recurrent_state(&state).evaluate(&[0.0; CompactController::INPUT_COUNT], &mut remembering);
+
+    assert_eq!(outputs, [fresh, remembering]);
+    assert_eq!(CompactControllerBank::<2>::INSTANCE_COUNT, 2);
+  }
+
+  #[test]
+  fn lanes_match_separate_instances() {
+    let mut bank = PreciseControllerBank::<7>::default();
+    let mut instances = [PreciseController::default(); 7];
+
+    for step in 0..16 {
+      let inputs: [[f32; 7]; PreciseController::INPUT_COUNT] = core::array::from_fn(|j| core::array::from_fn(|agent| (step + j * agent) as f32 * 0.1 - 0.4));
+      let mut outputs = [[0.0; 7]; PreciseController::OUTPUT_COUNT];
+      bank.evaluate_lanes(&inputs, &mut outputs);
+
+      for (agent, instance) in instances.iter_mut().enumerate() {
+        let mut expected = [0.0; PreciseController::OUTPUT_COUNT];
+        instance.evaluate(&core::array::from_fn(|j| inputs[j][agent]), &mut expected);
+        for (k, expected) in expected.iter().enumerate() { assert_eq!(outputs[k][agent], *expected); }
+
+        // one row of state per instance
+        assert_eq!(&bank.recurrent_state()[agent], instance.recurrent_state());
+      }
+    }
+  }
+
+  #[test]
+  fn large_banks_are_chunked() {
+    // several chunks of lanes, and a partial one
+    const AGENTS: usize = 4 * ControllerBank::<1>::LANES + 3;
+    let mut bank = ControllerBank::<AGENTS>::default();
+    let mut instances = [Controller::default(); AGENTS];
+
+    for step in 0..8 {
+      let inputs: [[f64; Controller::INPUT_COUNT]; AGENTS] = core::array::from_fn(|agent| [(step + agent) as f64 * 0.03 - 0.5, (agent % 5) as f64 * 0.2]);
+      let mut outputs = [[0.0; Controller::OUTPUT_COUNT]; AGENTS];
+      bank.evaluate_all(&inputs, &mut outputs);
+
+      for (agent, instance) in instances.iter_mut().enumerate() {
+        let mut expected = [0.0; Controller::OUTPUT_COUNT];
+        instance.evaluate(&inputs[agent], &mut expected);
+        assert_eq!(outputs[agent], expected);
+        assert_eq!(&bank.instance_state(agent), instance.recurrent_state());
+      }
+    }
+  }
+}
+
+/// Check the slice, iterator, and sequence wrappers around `evaluate`
+mod slice_and_iterator_wrappers {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge")]
+  struct Stateless;
+
+  #[recurrent("./test_inputs/test_network_v1.cge", numeric_type = f32)]
+  struct Stateful;
+
+  #[test]
+  fn matches_evaluate() {
+    let inputs = [0.25, -0.5];
+    let mut expected = [0.0; Stateless::OUTPUT_COUNT];
+    Stateless::evaluate(&inputs, &mut expected);
+
+    let mut outputs = [0.0; Stateless::OUTPUT_COUNT];
+    assert_eq!(Stateless::try_evaluate_slice(&inputs[..], &mut outputs[..]), Ok(()));
+    assert_eq!(outputs, expected);
+    assert_eq!(Stateless::evaluate_iter(inputs.iter().copied()), Ok(expected));
+
+    // recurrent networks advance their state through the wrappers too
+    let (mut a, mut b, mut c) = (Stateful::default(), Stateful::default(), Stateful::default());
+    for step in 0..4 {
+      let inputs = [step as f32 * 0.1, 1.0];
+      let mut expected = [0.0; 1];
+      let mut outputs = [0.0; 1];
+      a.evaluate(&inputs, &mut expected);
+      b.try_evaluate_slice(&inputs, &mut outputs).unwrap();
+      assert_eq!(outputs, expected);
+      assert_eq!(c.evaluate_iter(inputs), Ok(expected));
+    }
+  }
+
+  #[test]
+  fn sequences_match_repeated_evaluation() {
+    let inputs: [[f32; 2]; 64] = core::array::from_fn(|step| [step as f32 * 0.01, 1.0 - step as f32 * 0.02]);
+
+    let mut expected = [[0.0; 1]; 64];
+    let mut network = Stateful::default();
+    for (inputs, outputs) in inputs.iter().zip(&mut expected) { network.evaluate(inputs, outputs); }
+
+    let mut sequenced = Stateful::default();
+    let mut outputs = [[0.0; 1]; 64];
+    assert_eq!(sequenced.evaluate_sequence(&inputs, &mut outputs), Ok(()));
+    assert_eq!(outputs, expected);
+    assert_eq!(sequenced.recurrent_state(), network.recurrent_state());
+
+    // two unrolled blocks of 32 continue from one another
+    let mut unrolled = Stateful::default();
+    let mut outputs = [[0.0; 1]; 64];
+    let (first, second) = outputs.split_at_mut(32);
+    unrolled.evaluate_unrolled::<32>(inputs[..32].try_into().unwrap(), first.try_into().unwrap());
+    unrolled.evaluate_unrolled::<32>(inputs[32..].try_into().unwrap(), second.try_into().unwrap());
+    assert_eq!(outputs, expected);
+    assert_eq!(unrolled.recurrent_state(), network.recurrent_state());
+
+    // stateless networks just evaluate each element
+    let mut outputs = [[0.0; 3]; 4];
+    Stateless::evaluate_sequence(&[[0.1, 0.2], [0.3, 0.4], [0.5, 0.6], [0.7, 0.8]], &mut outputs).unwrap();
+    for (inputs, outputs) in [[0.1, 0.2], [0.3, 0.4], [0.5, 0.6], [0.7, 0.8]].iter().zip(outputs) {
+      let mut expected = [0.0; 3];
+      Stateless::evaluate(inputs, &mut expected);
+      assert_eq!(outputs, expected);
+    }
+  }
+
+  #[test]
+  fn sequence_length_mismatch_fails() {
+    let mut outputs = [[0.0; 3]; 2];
+    assert_eq!(Stateless::evaluate_sequence(&[[1.0; 2]; 3], &mut outputs), Err(DimensionError::Sequence { inputs: 3, outputs: 2 }));
+    assert_eq!(outputs, [[0.0; 3]; 2]);
+
+    // (nothing is evaluated, so recurrent state is untouched)
+    let mut network = Stateful::default();
+    assert!(network.evaluate_sequence(&[[1.0; 2]; 2], &mut [[0.0; 1]; 3]).is_err());
+    assert_eq!(network.recurrent_state(), Stateful::default().recurrent_state());
+  }
+
+  #[test]
+  fn unrolled_steps_are_in_order() {
+    use const_cge::unroll::Unrolled;
+
+    let mut visited = [usize::MAX; unroll::MAX_UNROLLED];
+    let mut count = 0;
+    <[(); unroll::MAX_UNROLLED] as Unrolled>::steps(|step| { visited[count] = step; count += 1; });
+    assert_eq!(visited, core::array::from_fn(|step| step));
+
+    count = 0;
+    <[(); 0] as Unrolled>::steps(|_| count += 1);
+    <[(); 3] as Unrolled>::steps(|_| count += 1);
+    assert_eq!(count, 3);
+  }
+
+  #[test]
+  fn rejects_wrong_dimensions() {
+    let mut outputs = [0.0; 8];
+    assert_eq!(Stateless::try_evaluate_slice(&[1.0], &mut outputs[..3]), Err(DimensionError::Inputs { expected: 2, found: 1 }));
+    assert_eq!(Stateless::try_evaluate_slice(&[1.0, 2.0], &mut outputs), Err(DimensionError::Outputs { expected: 3, found: 8 }));
+    assert_eq!(Stateless::evaluate_iter([1.0]), Err(DimensionError::Inputs { expected: 2, found: 1 }));
+    assert_eq!(Stateless::evaluate_iter([1.0, 2.0, 3.0]), Err(DimensionError::Inputs { expected: 2, found: 3 }));
+  }
+
+  #[test]
+  fn endless_iterators_fail() {
+    // reads one element past `INPUT_COUNT`, then gives up
+    let ring = [0.25, -0.5, 1.0];
+    assert_eq!(Stateless::evaluate_iter(ring.iter().copied().cycle()), Err(DimensionError::Inputs { expected: 2, found: 3 }));
+    assert_eq!(Stateful::default().evaluate_iter(core::iter::repeat(1.0)), Err(DimensionError::Inputs { expected: 2, found: 3 }));
+  }
+}
+
+/// Check that stateless networks with `const` activations can be evaluated at compile time
+mod const_evaluation {
+  use crate as const_cge;
+  use const_cge::*;
+
+  /// relu(0.5 x0 - 0.25 x1 + 0.1), then weighted by 2
+  #[nonrecurrent(r#"{"version":"1","network":{"metadata":{"description":null},"activation":"relu","genome":[
+    {"kind":"neuron","id":0,"num_inputs":3,"weight":2.0},
+    {"kind":"input","id":0,"weight":0.5},
+    {"kind":"input","id":1,"weight":-0.25},
+    {"kind":"bias","value":0.1}
+  ],"recurrent_state":null,"extra":null}}"#)]
+  struct Ramp;
+
+  /// Outputs of the network along `x0` (with `x1 = 1`), computed by rustc
+  const TABLE: [f64; 4] = {
+    let mut table = [0.0; 4];
+    let mut i = 0;
+    while i < table.len() {
+      let mut output = [0.0];
+      Ramp::evaluate(&[i as f64, 1.0], &mut output);
+      table[i] = output[0];
+      i += 1;
+    }
+    table
+  };
+
+  // relu clamps the first entry (0 - 0.25 + 0.1 < 0), the rest ramp upwards
+  const _: () = assert!(TABLE[0] == 0.0 && TABLE[3] > TABLE[2]);
+
+  #[test]
+  fn table_matches_runtime_evaluation() {
+    for (i, &entry) in TABLE.iter().enumerate() {
+      let mut output = [0.0];
+      Ramp::evaluate(&[i as f64, 1.0], &mut output);
+      assert_eq!(entry, output[0]);
+    }
+  }
+}
+
+/// `mode = lut(...)` against arithmetic twins of the same networks
+mod lut_mode {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[nonrecurrent("./test_inputs/lut_wave.cge")]
+  struct Wave;
+
+  #[nonrecurrent("./test_inputs/lut_wave.cge", mode = lut(resolution = 256, ranges = [-2.0..2.0]))]
+  struct WaveLut;
+
+  #[nonrecurrent("./test_inputs/lut_surface.cge")]
+  struct Surface;
+
+  #[nonrecurrent("./test_inputs/lut_surface.cge", mode = lut(resolution = 64, ranges = [-1.0..1.0, -1.0..1.0]))]
+  struct SurfaceLut;
+
+  // `verify` samples inside the ranges, and allows for the error measured at compile time (after `numeric_type` conversion)
+  #[nonrecurrent("./test_inputs/lut_wave.cge", verify = 2000, mode = lut(resolution = 256, ranges = [-2.0..2.0]))]
+  struct VerifiedWave;
+
+  #[nonrecurrent("./test_inputs/lut_wave.cge", numeric_type = f32, verify = 2000, mode = lut(resolution = 8, ranges = [-0.5..1.5]))]
+  struct VerifiedCoarseWave32;
+
+  #[nonrecurrent("./test_inputs/lut_surface.cge", numeric_type = f32, verify = 500, mode = lut(resolution = 64, ranges = [-1.0..1.0, -1.0..1.0]))]
+  struct VerifiedSurface32;
+
+  // golden vectors describe the exact network, the lookup table is allowed its measured error
+  #[nonrecurrent("./test_inputs/lut_wave.cge", numeric_type = f32, expect = [([0.3], [0.43696891867738635])], mode = lut(resolution = 8, ranges = [-0.5..1.5]))]
+  struct ExpectedCoarseWave32;
+
+  #[test]
+  fn one_input_interpolation_is_close() {
+    for i in 0..=400 {
+      let x = -2.0 + i as f64 / 100.0;
+      let (mut exact, mut approximate) = ([0.0], [0.0]);
+      Wave::evaluate(&[x], &mut exact);
+      WaveLut::evaluate(&[x], &mut approximate);
+      assert!((exact[0] - approximate[0]).abs() < 1e-3, "x = {}: {} vs {}", x, exact[0], approximate[0]);
+    }
+  }
+
+  #[test]
+  fn one_input_samples_are_exact_at_range_ends() {
+    for x in [-2.0, 2.0] {
+      let (mut exact, mut approximate) = ([0.0], [0.0]);
+      Wave::evaluate(&[x], &mut exact);
+      WaveLut::evaluate(&[x], &mut approximate);
+      assert!((exact[0] - approximate[0]).abs() < 1e-12);
+    }
+  }
+
+  #[test]
+  fn inputs_outside_range_are_clamped() {
+    let (mut low, mut high, mut clamped) = ([0.0], [0.0], [0.0]);
+    WaveLut::evaluate(&[-2.0], &mut low);
+    WaveLut::evaluate(&[2.0], &mut high);
+
+    WaveLut::evaluate(&[-100.0], &mut clamped);
+    assert_eq!(clamped, low);
+    WaveLut::evaluate(&[100.0], &mut clamped);
+    assert_eq!(clamped, high);
+  }
+
+  #[test]
+  fn two_input_interpolation_is_close() {
+    for i in 0..=50 {
+      for j in 0..=50 {
+        let inputs = [-1.0 + i as f64 / 25.0, -1.0 + j as f64 / 25.0];
+        let (mut exact, mut approximate) = ([0.0], [0.0]);
+        Surface::evaluate(&inputs, &mut exact);
+        SurfaceLut::evaluate(&inputs, &mut approximate);
+        assert!((exact[0] - approximate[0]).abs() < 1e-3, "{:?}: {} vs {}", inputs, exact[0], approximate[0]);
+      }
+    }
+  }
+
+  #[test]
+  fn lut_performs_no_activation_calls() {
+    assert_eq!(WaveLut::ACTIVATION_CALLS, 0);
+    assert_ne!(Wave::ACTIVATION_CALLS, 0);
+  }
+}
+
+/// `mode = compact` must be bitwise identical to `mode = arithmetic`
+mod compact_mode {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[recurrent("./test_inputs/fig_5_3_paper_second_output.cge")]
+  struct Arithmetic;
+
+  #[recurrent("./test_inputs/fig_5_3_paper_second_output.cge", mode = compact, verify = 200)]
+  struct Compact;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge", numeric_type = f32)]
+  struct Arithmetic32;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge", numeric_type = f32, mode = compact)]
+  struct Compact32;
+
   #[test]
-  fn recurrent_previous_value() {
-    // statically create network (enforce recurrency, that is central to what we're testing!)
-    #[recurrent("./test_inputs/test_network_recurrent.cge", numeric_type = f64)]
-    struct TestNet;
-    let mut net = TestNet::default();
+  fn recurrent_matches_arithmetic() {
+    let (mut arithmetic, mut compact) = (Arithmetic::default(), Compact::default());
 
-    for (input, correct) in [([], [1.0]), ([], [4.0])] {
-      let mut static_outputs = [0.0; 1];
-      net.evaluate(&input, &mut static_outputs);
-      assert_eq!(static_outputs, correct);
+    for step in 0..64 {
+      let inputs = [(step as f64 * 0.37).sin(), (step as f64 * 0.11).cos()];
+      let (mut expected, mut outputs) = ([0.0; Arithmetic::OUTPUT_COUNT], [0.0; Compact::OUTPUT_COUNT]);
+      arithmetic.evaluate(&inputs, &mut expected);
+      compact.evaluate(&inputs, &mut outputs);
+
+      assert_eq!(outputs.map(f64::to_bits), expected.map(f64::to_bits));
+      assert_eq!(compact.recurrent_state(), arithmetic.recurrent_state());
+    }
+  }
+
+  #[test]
+  fn stateless_matches_arithmetic() {
+    for step in 0..64 {
+      let inputs = [step as f32 * 0.05 - 1.6, 0.5 - step as f32 * 0.02];
+      let (mut expected, mut outputs) = ([0.0; 3], [0.0; 3]);
+      Arithmetic32::evaluate(&inputs, &mut expected);
+      Compact32::evaluate(&inputs, &mut outputs);
+      assert_eq!(outputs.map(f32::to_bits), expected.map(f32::to_bits));
+    }
+  }
+
+  #[test]
+  fn constants_describe_the_same_network() {
+    assert_eq!(Compact::PERSISTENT_SIZE, Arithmetic::PERSISTENT_SIZE);
+    assert_eq!(Compact::MUL_COUNT,       Arithmetic::MUL_COUNT);
+    assert_eq!(Compact::ADD_COUNT,       Arithmetic::ADD_COUNT);
+    assert_eq!(Compact::DEPTH,           Arithmetic::DEPTH);
+  }
+}
+
+/// `mode = simd` must be bitwise identical to `mode = arithmetic`
+#[cfg(feature = "simd")]
+mod simd_mode {
+  use crate as const_cge;
+  use const_cge::*;
+
+  #[recurrent("./test_inputs/fig_5_3_paper_second_output.cge")]
+  struct Arithmetic;
+
+  #[recurrent("./test_inputs/fig_5_3_paper_second_output.cge", mode = simd, verify = 200)]
+  struct Simd;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge", numeric_type = f32)]
+  struct Arithmetic32;
+
+  #[nonrecurrent("./test_inputs/test_network_multi_output.cge", numeric_type = f32, mode = simd)]
+  struct Simd32;
+
+  #[test]
+  fn recurrent_matches_arithmetic() {
+    let (mut arithmetic, mut simd) = (Arithmetic::default(), Simd::default());
+
+    for step in 0..64 {
+      let inputs = [(step as f64 * 0.37).sin(), (step as f64 * 0.11).cos()];
+      let (mut expected, mut outputs) = ([0.0; Arithmetic::OUTPUT_COUNT], [0.0; Simd::OUTPUT_COUNT]);
+      arithmetic.evaluate(&inputs, &mut expected);
+      simd.evaluate(&inputs, &mut outputs);
+
+      assert_eq!(outputs.map(f64::to_bits), expected.map(f64::to_bits));
+      assert_eq!(simd.recurrent_state(), arithmetic.recurrent_state());
+    }
+  }
+
+  #[test]
+  fn stateless_matches_arithmetic() {
+    for step in 0..64 {
+      let inputs = [step as f32 * 0.05 - 1.6, 0.5 - step as f32 * 0.02];
+      let (mut expected, mut outputs) = ([0.0; 3], [0.0; 3]);
+      Arithmetic32::evaluate(&inputs, &mut expected);
+      Simd32::evaluate(&inputs, &mut outputs);
+      assert_eq!(outputs.map(f32::to_bits), expected.map(f32::to_bits));
+    }
+  }
+
+  /// Padding terms (`0.0 * -0.0`) must not disturb a neuron whose true sum is `-0.0`
+  #[test]
+  fn padding_preserves_negative_zero() {
+    let sums = simd::layer(&[[-0.0, 1.0], [0.0, 2.0]], &[[1.0, 1.0], [-0.0, 1.0]], |x: f64| x);
+    assert_eq!(sums.map(f64::to_bits), [(-0.0f64).to_bits(), 3.0f64.to_bits()]);
+  }
+}
+
+/// Error of `fma` and `accumulate` versus the `cge` reference, on a neuron with a long fan-in
+mod precision_options {
+  use crate as const_cge;
+  use const_cge::*;
+  use cge::{Network, WithRecurrentState};
+
+  const PATH: &str = "./test_inputs/long_fan_in.cge";
+
+  #[nonrecurrent("./test_inputs/long_fan_in.cge", numeric_type = f32)]
+  struct Plain32;
+
+  #[nonrecurrent("./test_inputs/long_fan_in.cge", numeric_type = f32, fma = true)]
+  struct Fused32;
+
+  #[nonrecurrent("./test_inputs/long_fan_in.cge", numeric_type = f32, accumulate = f64)]
+  struct Wide32;
+
+  #[nonrecurrent("./test_inputs/long_fan_in.cge", numeric_type = f32, accumulate = f64, fma = true, verify = 100)]
+  struct WideFused32;
+
+  #[nonrecurrent("./test_inputs/long_fan_in.cge", fma = true, verify = 100)]
+  struct Fused64;
+
+  // deferred weighting through forward jumpers, recurrent jumpers, and multiple outputs
+  #[recurrent("./test_inputs/fig_5_3_paper_second_output.cge", numeric_type = f32, accumulate = f64, fma = true, verify = 200)]
+  struct WideFusedRecurrent32;
+
+  /// Largest absolute error of `evaluate` versus `cge`, over a fixed set of inputs in [-1, +1]
+  fn max_error(evaluate: impl Fn(&[f32; 8]) -> f64) -> f64 {
+    let (mut runtime, _, _) = Network::<f64>::load_file::<(), _>(PATH, WithRecurrentState(false)).unwrap();
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut max_error = 0f64;
+
+    for _ in 0..2000 {
+      let inputs: [f32; 8] = core::array::from_fn(|_| {
+        state ^= state << 13; state ^= state >> 7; state ^= state << 17;
+        (state >> 40) as f32 / (1u64 << 24) as f32 * 2.0 - 1.0
+      });
+
+      let expected = runtime.evaluate(&inputs.map(f64::from)).unwrap()[0];
+      max_error = max_error.max((evaluate(&inputs) - expected).abs());
     }
+
+    max_error
+  }
+
+  fn evaluate_f32(evaluate: fn(&[f32; 8], &mut [f32; 1])) -> impl Fn(&[f32; 8]) -> f64 {
+    move |inputs| { let mut outputs = [0.0]; evaluate(inputs, &mut outputs); outputs[0] as f64 }
+  }
+
+  /// The bounds documented in the README (`# Precision`)
+  #[test]
+  fn documented_error_bounds() {
+    let plain = max_error(evaluate_f32(Plain32::evaluate));
+    let fused = max_error(evaluate_f32(Fused32::evaluate));
+    let wide = max_error(evaluate_f32(Wide32::evaluate));
+    let wide_fused = max_error(evaluate_f32(WideFused32::evaluate));
+    let fused_64 = max_error(|inputs| { let mut outputs = [0.0]; Fused64::evaluate(&inputs.map(f64::from), &mut outputs); outputs[0] });
+
+    // measured: plain 1.0e-5, fused 8.4e-6, wide (and wide fused) 8.2e-7, fused f64 8.9e-15
+    assert!(plain < 2e-5);
+    assert!(fused < 2e-5);
+    assert!(wide < 1e-6 && wide < plain / 5.0);
+    assert!(wide_fused < 1e-6 && wide_fused < plain / 5.0);
+    assert!(fused_64 < 1e-13);
+  }
+
+  #[test]
+  fn default_arithmetic_is_unchanged() {
+    // `accumulate` equal to `numeric_type`, without `fma`, is exactly the default
+    #[nonrecurrent("./test_inputs/long_fan_in.cge", numeric_type = f32, accumulate = f32, fma = false)]
+    struct Explicit32;
+
+    let inputs = [0.5, -0.25, 0.125, 1.0, -1.0, 0.75, -0.5, 0.3];
+    let (mut a, mut b) = ([0.0], [0.0]);
+    Plain32::evaluate(&inputs, &mut a);
+    Explicit32::evaluate(&inputs, &mut b);
+    assert_eq!(a[0].to_bits(), b[0].to_bits());
+    assert_eq!(Plain32::MUL_COUNT, Fused32::MUL_COUNT);
+    assert_eq!(Plain32::ADD_COUNT, Fused32::ADD_COUNT);
   }
 }
 
+/// Random networks generated by `examples/random_corpus.rs` (forward, nested, and recurrent jumpers, biases,
+/// multiple outputs, every activation), each `verify`-ed against the `cge` runtime.
+mod random_corpus {
+  include!("../test_inputs/random_corpus/harness.rs");
+}
+
 /// Test `./test_inputs/test_network_v1.cge`
 mod test_network_v1 {
   use crate as const_cge; 
-  use assert_float_eq::*;
   use const_cge::*;
   use cge::*;
   use proptest::{
@@ -89,7 +1535,7 @@ mod test_network_v1 {
       assert_eq!(static_outputs, runtime_outputs);
       // assert_eq!(static_outputs.len(), runtime_outputs.len());
       // for (s, r) in static_outputs.iter().zip(runtime_outputs.iter()) {
-      //   assert_float_absolute_eq!(s, r);
+      //   assert_float_absolute_eq!(*s, *r);
       // }
     });
   }
@@ -126,15 +1572,14 @@ mod test_network_v1 {
         assert_eq!(static_outputs, runtime_outputs);
         // assert_eq!(static_outputs.len(), runtime_outputs.len());
         // for (s, r) in static_outputs.iter().zip(runtime_outputs.iter()) {
-        //   assert_float_absolute_eq!(s, r);
+        //   assert_float_absolute_eq!(*s, *r);
         // }
       }
     });
   }
 }
 
-/// Test `./test_inputs/fig_5_3_paper.cge` 
-/// Network from [Figure 5.3](https://www.semanticscholar.org/paper/Towards-a-unified-approach-to-learning-and-Kassahun/f0a39d0e8e891cb9ff6a81172f0c5ebb37ea52e9/figure/30) of [the paper](https://www.semanticscholar.org/paper/Towards-a-unified-approach-to-learning-and-Kassahun/f0a39d0e8e891cb9ff6a81172f0c5ebb37ea52e9)
+/// Test `./test_inputs/with_extra_data_v1.cge`
 mod with_extra_data_v1 {
   use assert_float_eq::*;
   use crate as const_cge; 
@@ -177,7 +1622,7 @@ mod with_extra_data_v1 {
       // const_cge uses `libm` by default, and the activation functions are not bitwise identical.
       assert_eq!(static_outputs.len(), runtime_outputs.len());
       for (s, r) in static_outputs.iter().zip(runtime_outputs.iter()) {
-        assert_float_absolute_eq!(s, r);
+        assert_float_absolute_eq!(*s, *r);
       }
     });
   }
@@ -214,84 +1659,221 @@ mod with_extra_data_v1 {
         // const_cge uses `libm` by default, and the activation functions are not bitwise identical.
         assert_eq!(static_outputs.len(), runtime_outputs.len());
         for (s, r) in static_outputs.iter().zip(runtime_outputs.iter()) {
-          assert_float_absolute_eq!(s, r);
+          assert_float_absolute_eq!(*s, *r);
         }
       }
     });
   }
 }
 
-// /// Test `./test_inputs/fig_5_3_paper_plus_one.cge` 
-// /// Augmentation of `figure_5_3_paper` with an single additional neuron.
-// mod figure_5_3_paper_plus_one {
-//   use crate as const_cge; 
-//   use const_cge::*;
-//   use cge::*;
-//   use proptest::{
-//     prelude::*,
-//     collection::vec,
-//     array::uniform2
-//   };
-
-//   /// Static and dynamic constructions of the network should
-//   /// have identical output for all inputs.
-//   /// 
-//   /// - Network memory is wiped after every test.
-//   #[test]
-//   fn recurrent_memorywipe_50k_trials() {
-//     // statically create network
-//     #[recurrent("./test_inputs/fig_5_3_paper_plus_one.cge", numeric_type = f64)]
-//     struct TestNet;
-
-//     // dynamically load the exact same network
-//     let (runtime, _, _) = Network::load_file::<(), _>("./test_inputs/fig_5_3_paper_plus_one.cge", WithRecurrentState(false))
-//       .expect("Failed to dynamically load CGE file");
-
-//     // gimme 50,000 `[f64; 2]`; where each f64 falls in [-1, +1]
-//     proptest!(ProptestConfig::with_cases(50_000), |(input_vector in uniform2(-1.0f64..1.0f64))| {
-//       let mut net = TestNet::default();
-//       let mut runtime = runtime.clone();
-
-//       let static_outputs = {
-//         let mut outputs = [0.0; 1];
-//         net.evaluate(&input_vector, &mut outputs);
-//         outputs.to_vec()
-//       };
-
-//       let runtime_outputs = runtime.evaluate(&input_vector[..]).unwrap();
-//       assert_eq!(static_outputs, runtime_outputs);
-//     });
-//   }
-
-//   /// - Static and dynamic constructions of the network should
-//   ///   have identical output for all inputs, with memory, out to 5000 eval cycles.
-//   /// - Repeat this trial 50 times
-//   #[test]
-//   fn recurrent_5k_cycles_1k_trials() {
-//     // statically create network (enforce recurrency, that is central to what we're testing!)
-//     #[recurrent("./test_inputs/fig_5_3_paper_plus_one.cge", numeric_type = f64)]
-//     struct TestNet;
-
-//     // dynamically load the exact same network
-//     let (runtime, _, _) = Network::load_file::<(), _>("./test_inputs/fig_5_3_paper_plus_one.cge", WithRecurrentState(false))
-//       .expect("Failed to dynamically load CGE file");
-
-//     // gimme 50 `vec<[f64; 2]>`, each 5k elements long, where each f64 falls in [-1, +1]
-//     proptest!(ProptestConfig::with_cases(50), |(input_vectors in vec(uniform2(-1.0f64..1.0f64), 5000..=5000))| {
-//       let mut net = TestNet::default();  // start them both in the same state
-//       let mut runtime = runtime.clone(); // start them both in the same state
-
-//       // run 1000 evals deep
-//       for input_vector in input_vectors {
-//         let static_outputs = {
-//           let mut outputs = [0.0; 1];
-//           net.evaluate(&input_vector, &mut outputs);
-//           outputs.to_vec()
-//         };
-
-//         let runtime_outputs = runtime.evaluate(&input_vector[..]).unwrap();
-//         assert_eq!(static_outputs, runtime_outputs);
-//       }
-//     });
-//   }
-// }
+/// Test `./test_inputs/fig_5_3_paper.cge` 
+/// Network from [Figure 5.3](https://www.semanticscholar.org/paper/Towards-a-unified-approach-to-learning-and-Kassahun/f0a39d0e8e891cb9ff6a81172f0c5ebb37ea52e9/figure/30) of [the paper](https://www.semanticscholar.org/paper/Towards-a-unified-approach-to-learning-and-Kassahun/f0a39d0e8e891cb9ff6a81172f0c5ebb37ea52e9)
+mod figure_5_3_paper {
+  use crate as const_cge; 
+  use assert_float_eq::*;
+  use const_cge::*;
+  use cge::*;
+  use proptest::{
+    prelude::*,
+    collection::vec,
+    array::uniform2
+  };
+
+  /// Worked by hand from the figure (linear activation, recurrent state starts at zero).
+  /// - the forward jumper reads neuron 3's unweighted output, weighted by the jumper (0.3), not by neuron 3 (0.9)
+  #[test]
+  fn hand_computed() {
+    #[recurrent("./test_inputs/fig_5_3_paper.cge", numeric_type = f64)]
+    struct TestNet;
+    let mut net = TestNet::default();
+
+    // n3 = 0.1 + 0.4 = 0.5, n2 = 0.3 n3 + 0.7 + 0.8 + 0.2 * 0, n1 = 0.9 n3 + 0.5, n0 = 0.8 n1 + 0.2 n2, output = 0.6 n0
+    // then again, with the recurrent jumper reading n0 = 1.09
+    for correct in [0.654, 0.68016] {
+      let mut output = [0.0; 1];
+      net.evaluate(&[1.0, 1.0], &mut output);
+      assert_float_absolute_eq!(output[0], correct);
+    }
+  }
+
+  /// Static and dynamic constructions of the network should
+  /// have identical output for all inputs.
+  /// 
+  /// - Network memory is wiped after every test.
+  #[test]
+  fn recurrent_memorywipe_50k_trials() {
+    // statically create network
+    #[recurrent("./test_inputs/fig_5_3_paper.cge", numeric_type = f64)]
+    struct TestNet;
+
+    // dynamically load the exact same network
+    let (mut runtime, _, _) = Network::load_file::<(), _>("./test_inputs/fig_5_3_paper.cge", WithRecurrentState(false))
+      .expect("Failed to dynamically load CGE file");
+    runtime.clear_state();
+
+    // gimme 50,000 `[f64; 2]`; where each f64 falls in [-1, +1]
+    proptest!(ProptestConfig::with_cases(50_000), |(input_vector in uniform2(-1.0f64..1.0f64))| {
+      let mut net = TestNet::default();
+      let mut runtime = runtime.clone();
+
+      let static_outputs = {
+        let mut outputs = [0.0; TestNet::OUTPUT_COUNT];
+        net.evaluate(&input_vector, &mut outputs);
+        outputs.to_vec()
+      };
+
+      let runtime_outputs = runtime.evaluate(&input_vector[..]).unwrap();
+      assert_eq!(static_outputs, runtime_outputs);
+    });
+  }
+
+  /// - Static and dynamic constructions of the network should
+  ///   have identical output for all inputs, with memory, out to 5000 eval cycles.
+  /// - Repeat this trial 50 times
+  #[test]
+  fn recurrent_5k_cycles_1k_trials() {
+    // statically create network (enforce recurrency, that is central to what we're testing!)
+    #[recurrent("./test_inputs/fig_5_3_paper.cge", numeric_type = f64)]
+    struct TestNet;
+
+    // dynamically load the exact same network
+    let (mut runtime, _, _) = Network::load_file::<(), _>("./test_inputs/fig_5_3_paper.cge", WithRecurrentState(false))
+      .expect("Failed to dynamically load CGE file");
+    runtime.clear_state();
+
+    // gimme 50 `vec<[f64; 2]>`, each 5k elements long, where each f64 falls in [-1, +1]
+    proptest!(ProptestConfig::with_cases(50), |(input_vectors in vec(uniform2(-1.0f64..1.0f64), 5000..=5000))| {
+      let mut net = TestNet::default();  // start them both in the same state
+      let mut runtime = runtime.clone(); // start them both in the same state
+
+      for input_vector in input_vectors {
+        let static_outputs = {
+          let mut outputs = [0.0; TestNet::OUTPUT_COUNT];
+          net.evaluate(&input_vector, &mut outputs);
+          outputs.to_vec()
+        };
+
+        let runtime_outputs = runtime.evaluate(&input_vector[..]).unwrap();
+        assert_eq!(static_outputs, runtime_outputs);
+      }
+    });
+  }
+}
+
+/// Test `./test_inputs/fig_5_3_paper_plus_one.cge` 
+/// Augmentation of `figure_5_3_paper` with an single additional neuron (nested in neuron 3, which a forward jumper reaches).
+mod figure_5_3_paper_plus_one {
+  use crate as const_cge; 
+  use const_cge::*;
+  use cge::*;
+  use proptest::{
+    prelude::*,
+    collection::vec,
+    array::uniform2
+  };
+
+  /// Static and dynamic constructions of the network should
+  /// have identical output for all inputs.
+  /// 
+  /// - Network memory is wiped after every test.
+  #[test]
+  fn recurrent_memorywipe_50k_trials() {
+    // statically create network
+    #[recurrent("./test_inputs/fig_5_3_paper_plus_one.cge", numeric_type = f64)]
+    struct TestNet;
+
+    // dynamically load the exact same network
+    let (mut runtime, _, _) = Network::load_file::<(), _>("./test_inputs/fig_5_3_paper_plus_one.cge", WithRecurrentState(false))
+      .expect("Failed to dynamically load CGE file");
+    runtime.clear_state();
+
+    // gimme 50,000 `[f64; 2]`; where each f64 falls in [-1, +1]
+    proptest!(ProptestConfig::with_cases(50_000), |(input_vector in uniform2(-1.0f64..1.0f64))| {
+      let mut net = TestNet::default();
+      let mut runtime = runtime.clone();
+
+      let static_outputs = {
+        let mut outputs = [0.0; TestNet::OUTPUT_COUNT];
+        net.evaluate(&input_vector, &mut outputs);
+        outputs.to_vec()
+      };
+
+      let runtime_outputs = runtime.evaluate(&input_vector[..]).unwrap();
+      assert_eq!(static_outputs, runtime_outputs);
+    });
+  }
+
+  /// - Static and dynamic constructions of the network should
+  ///   have identical output for all inputs, with memory, out to 5000 eval cycles.
+  /// - Repeat this trial 50 times
+  #[test]
+  fn recurrent_5k_cycles_1k_trials() {
+    // statically create network (enforce recurrency, that is central to what we're testing!)
+    #[recurrent("./test_inputs/fig_5_3_paper_plus_one.cge", numeric_type = f64)]
+    struct TestNet;
+
+    // dynamically load the exact same network
+    let (mut runtime, _, _) = Network::load_file::<(), _>("./test_inputs/fig_5_3_paper_plus_one.cge", WithRecurrentState(false))
+      .expect("Failed to dynamically load CGE file");
+    runtime.clear_state();
+
+    // gimme 50 `vec<[f64; 2]>`, each 5k elements long, where each f64 falls in [-1, +1]
+    proptest!(ProptestConfig::with_cases(50), |(input_vectors in vec(uniform2(-1.0f64..1.0f64), 5000..=5000))| {
+      let mut net = TestNet::default();  // start them both in the same state
+      let mut runtime = runtime.clone(); // start them both in the same state
+
+      for input_vector in input_vectors {
+        let static_outputs = {
+          let mut outputs = [0.0; TestNet::OUTPUT_COUNT];
+          net.evaluate(&input_vector, &mut outputs);
+          outputs.to_vec()
+        };
+
+        let runtime_outputs = runtime.evaluate(&input_vector[..]).unwrap();
+        assert_eq!(static_outputs, runtime_outputs);
+      }
+    });
+  }
+}
+
+/// Test `./test_inputs/fig_5_3_paper_second_output.cge`
+/// Augmentation of `figure_5_3_paper` with a second output neuron, which reaches into neuron 2
+/// (which itself contains a forward jumper) through a forward jumper, and remembers its own previous output.
+mod figure_5_3_paper_second_output {
+  use crate as const_cge;
+  use const_cge::*;
+  use cge::*;
+  use proptest::{
+    prelude::*,
+    collection::vec,
+    array::uniform2
+  };
+
+  /// - Static and dynamic constructions of the network should
+  ///   have identical output for all inputs, with memory, out to 1000 eval cycles.
+  /// - Repeat this trial 50 times
+  #[test]
+  fn recurrent_1k_cycles_50_trials() {
+    #[recurrent("./test_inputs/fig_5_3_paper_second_output.cge", numeric_type = f64)]
+    struct TestNet;
+
+    // dynamically load the exact same network
+    let (mut runtime, _, _) = Network::load_file::<(), _>("./test_inputs/fig_5_3_paper_second_output.cge", WithRecurrentState(false))
+      .expect("Failed to dynamically load CGE file");
+    runtime.clear_state();
+    assert_eq!(TestNet::OUTPUT_COUNT, 2);
+
+    proptest!(ProptestConfig::with_cases(50), |(input_vectors in vec(uniform2(-1.0f64..1.0f64), 1000..=1000))| {
+      let mut net = TestNet::default();  // start them both in the same state
+      let mut runtime = runtime.clone(); // start them both in the same state
+
+      for input_vector in input_vectors {
+        let mut outputs = [0.0; TestNet::OUTPUT_COUNT];
+        net.evaluate(&input_vector, &mut outputs);
+
+        let runtime_outputs = runtime.evaluate(&input_vector[..]).unwrap();
+        assert_eq!(outputs.to_vec(), runtime_outputs);
+      }
+    });
+  }
+}
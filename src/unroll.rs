@@ -0,0 +1,36 @@
+//! Helpers used by the generated `evaluate_unrolled::<K>`.
+
+/// The largest `K` supported by `evaluate_unrolled::<K>` (longer blocks can use `evaluate_sequence`).
+pub const MAX_UNROLLED: usize = 64;
+
+/// Implemented by `[(); K]` for every `K` up to [`MAX_UNROLLED`]: calls `step(0)`, `step(1)`, ..., `step(K - 1)`,
+/// written out one after another (no loop).
+pub trait Unrolled {
+  fn steps(step: impl FnMut(usize));
+}
+
+/// `impl Unrolled for [(); K]`, for each `K` given (the indices before `K` are accumulated in brackets)
+macro_rules! unrolled {
+  ([$($done:literal)*]) => {};
+  ([$($done:literal)*] $next:literal $($rest:literal)*) => {
+    impl Unrolled for [(); $next + 1] {
+      #[inline(always)]
+      fn steps(mut step: impl FnMut(usize)) {
+        $( step($done); )*
+        step($next);
+      }
+    }
+
+    unrolled!([$($done)* $next] $($rest)*);
+  };
+}
+
+impl Unrolled for [(); 0] {
+  #[inline(always)]
+  fn steps(_: impl FnMut(usize)) {}
+}
+
+unrolled!([]
+   0  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+  32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+);
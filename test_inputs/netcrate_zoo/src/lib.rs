@@ -0,0 +1,33 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+const_cge::netcrate!(plain = "../test_network_v1.cge");
+
+const_cge::netcrate!(
+  vision::edges = "../test_network_v1.cge",
+  version       = "1.2.0",
+  license       = "MIT",
+  input_names   = ["brightness", "contrast"],
+  output_names  = ["edge"],
+  inputs        = 2,
+  outputs       = 1,
+  expect_file   = "../test_network_v1_expect.json"
+);
+
+const_cge::netcrate!(
+  control::cart_pole = "../fig_5_3_paper.cge",
+  version            = "0.3.1",
+  description        = "Balances a pole on a cart"
+);
+
+const_cge::netcrate!(
+  wave         = "../lut_wave.cge",
+  numeric_type = f32,
+  mode         = lut(resolution = 256, ranges = [-2.0..2.0])
+);
+
+// rustc builds this crate from the root of `const_cge` (not from here), so these paths only resolve against this crate's root
+#[const_cge::recurrent("../test_network_v1.cge", expect_file = "../test_network_v1_expect.json")]
+pub struct Resolved;
+
+#[const_cge::recurrent(file = "../fig_5_3_paper.cge")]
+pub struct ResolvedFile;
@@ -0,0 +1,101 @@
+// generated by `cargo run --example random_corpus`, do not edit
+mod net_000 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_000.cge", verify = 64)] struct Net000; #[const_cge::network("./test_inputs/random_corpus/net_000.cge", verify = 64, mode = compact)] struct Compact000; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_000.cge", verify = 64, mode = simd)] struct Simd000; }
+mod net_001 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_001.cge", verify = 64)] struct Net001; #[const_cge::network("./test_inputs/random_corpus/net_001.cge", verify = 64, mode = compact)] struct Compact001; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_001.cge", verify = 64, mode = simd)] struct Simd001; }
+mod net_002 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_002.cge", verify = 64)] struct Net002; #[const_cge::network("./test_inputs/random_corpus/net_002.cge", verify = 64, mode = compact)] struct Compact002; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_002.cge", verify = 64, mode = simd)] struct Simd002; }
+mod net_003 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_003.cge", verify = 64)] struct Net003; #[const_cge::network("./test_inputs/random_corpus/net_003.cge", verify = 64, mode = compact)] struct Compact003; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_003.cge", verify = 64, mode = simd)] struct Simd003; }
+mod net_004 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_004.cge", verify = 64)] struct Net004; #[const_cge::network("./test_inputs/random_corpus/net_004.cge", verify = 64, mode = compact)] struct Compact004; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_004.cge", verify = 64, mode = simd)] struct Simd004; }
+mod net_005 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_005.cge", verify = 64)] struct Net005; #[const_cge::network("./test_inputs/random_corpus/net_005.cge", verify = 64, mode = compact)] struct Compact005; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_005.cge", verify = 64, mode = simd)] struct Simd005; }
+mod net_006 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_006.cge", verify = 64)] struct Net006; #[const_cge::network("./test_inputs/random_corpus/net_006.cge", verify = 64, mode = compact)] struct Compact006; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_006.cge", verify = 64, mode = simd)] struct Simd006; }
+mod net_007 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_007.cge", verify = 64)] struct Net007; #[const_cge::network("./test_inputs/random_corpus/net_007.cge", verify = 64, mode = compact)] struct Compact007; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_007.cge", verify = 64, mode = simd)] struct Simd007; }
+mod net_008 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_008.cge", verify = 64)] struct Net008; #[const_cge::network("./test_inputs/random_corpus/net_008.cge", verify = 64, mode = compact)] struct Compact008; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_008.cge", verify = 64, mode = simd)] struct Simd008; }
+mod net_009 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_009.cge", verify = 64)] struct Net009; #[const_cge::network("./test_inputs/random_corpus/net_009.cge", verify = 64, mode = compact)] struct Compact009; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_009.cge", verify = 64, mode = simd)] struct Simd009; }
+mod net_010 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_010.cge", verify = 64)] struct Net010; #[const_cge::network("./test_inputs/random_corpus/net_010.cge", verify = 64, mode = compact)] struct Compact010; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_010.cge", verify = 64, mode = simd)] struct Simd010; }
+mod net_011 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_011.cge", verify = 64)] struct Net011; #[const_cge::network("./test_inputs/random_corpus/net_011.cge", verify = 64, mode = compact)] struct Compact011; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_011.cge", verify = 64, mode = simd)] struct Simd011; }
+mod net_012 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_012.cge", verify = 64)] struct Net012; #[const_cge::network("./test_inputs/random_corpus/net_012.cge", verify = 64, mode = compact)] struct Compact012; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_012.cge", verify = 64, mode = simd)] struct Simd012; }
+mod net_013 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_013.cge", verify = 64)] struct Net013; #[const_cge::network("./test_inputs/random_corpus/net_013.cge", verify = 64, mode = compact)] struct Compact013; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_013.cge", verify = 64, mode = simd)] struct Simd013; }
+mod net_014 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_014.cge", verify = 64)] struct Net014; #[const_cge::network("./test_inputs/random_corpus/net_014.cge", verify = 64, mode = compact)] struct Compact014; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_014.cge", verify = 64, mode = simd)] struct Simd014; }
+mod net_015 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_015.cge", verify = 64)] struct Net015; #[const_cge::network("./test_inputs/random_corpus/net_015.cge", verify = 64, mode = compact)] struct Compact015; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_015.cge", verify = 64, mode = simd)] struct Simd015; }
+mod net_016 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_016.cge", verify = 64)] struct Net016; #[const_cge::network("./test_inputs/random_corpus/net_016.cge", verify = 64, mode = compact)] struct Compact016; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_016.cge", verify = 64, mode = simd)] struct Simd016; }
+mod net_017 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_017.cge", verify = 64)] struct Net017; #[const_cge::network("./test_inputs/random_corpus/net_017.cge", verify = 64, mode = compact)] struct Compact017; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_017.cge", verify = 64, mode = simd)] struct Simd017; }
+mod net_018 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_018.cge", verify = 64)] struct Net018; #[const_cge::network("./test_inputs/random_corpus/net_018.cge", verify = 64, mode = compact)] struct Compact018; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_018.cge", verify = 64, mode = simd)] struct Simd018; }
+mod net_019 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_019.cge", verify = 64)] struct Net019; #[const_cge::network("./test_inputs/random_corpus/net_019.cge", verify = 64, mode = compact)] struct Compact019; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_019.cge", verify = 64, mode = simd)] struct Simd019; }
+mod net_020 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_020.cge", verify = 64)] struct Net020; #[const_cge::network("./test_inputs/random_corpus/net_020.cge", verify = 64, mode = compact)] struct Compact020; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_020.cge", verify = 64, mode = simd)] struct Simd020; }
+mod net_021 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_021.cge", verify = 64)] struct Net021; #[const_cge::network("./test_inputs/random_corpus/net_021.cge", verify = 64, mode = compact)] struct Compact021; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_021.cge", verify = 64, mode = simd)] struct Simd021; }
+mod net_022 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_022.cge", verify = 64)] struct Net022; #[const_cge::network("./test_inputs/random_corpus/net_022.cge", verify = 64, mode = compact)] struct Compact022; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_022.cge", verify = 64, mode = simd)] struct Simd022; }
+mod net_023 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_023.cge", verify = 64)] struct Net023; #[const_cge::network("./test_inputs/random_corpus/net_023.cge", verify = 64, mode = compact)] struct Compact023; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_023.cge", verify = 64, mode = simd)] struct Simd023; }
+mod net_024 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_024.cge", verify = 64)] struct Net024; #[const_cge::network("./test_inputs/random_corpus/net_024.cge", verify = 64, mode = compact)] struct Compact024; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_024.cge", verify = 64, mode = simd)] struct Simd024; }
+mod net_025 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_025.cge", verify = 64)] struct Net025; #[const_cge::network("./test_inputs/random_corpus/net_025.cge", verify = 64, mode = compact)] struct Compact025; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_025.cge", verify = 64, mode = simd)] struct Simd025; }
+mod net_026 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_026.cge", verify = 64)] struct Net026; #[const_cge::network("./test_inputs/random_corpus/net_026.cge", verify = 64, mode = compact)] struct Compact026; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_026.cge", verify = 64, mode = simd)] struct Simd026; }
+mod net_027 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_027.cge", verify = 64)] struct Net027; #[const_cge::network("./test_inputs/random_corpus/net_027.cge", verify = 64, mode = compact)] struct Compact027; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_027.cge", verify = 64, mode = simd)] struct Simd027; }
+mod net_028 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_028.cge", verify = 64)] struct Net028; #[const_cge::network("./test_inputs/random_corpus/net_028.cge", verify = 64, mode = compact)] struct Compact028; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_028.cge", verify = 64, mode = simd)] struct Simd028; }
+mod net_029 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_029.cge", verify = 64)] struct Net029; #[const_cge::network("./test_inputs/random_corpus/net_029.cge", verify = 64, mode = compact)] struct Compact029; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_029.cge", verify = 64, mode = simd)] struct Simd029; }
+mod net_030 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_030.cge", verify = 64)] struct Net030; #[const_cge::network("./test_inputs/random_corpus/net_030.cge", verify = 64, mode = compact)] struct Compact030; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_030.cge", verify = 64, mode = simd)] struct Simd030; }
+mod net_031 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_031.cge", verify = 64)] struct Net031; #[const_cge::network("./test_inputs/random_corpus/net_031.cge", verify = 64, mode = compact)] struct Compact031; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_031.cge", verify = 64, mode = simd)] struct Simd031; }
+mod net_032 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_032.cge", verify = 64)] struct Net032; #[const_cge::network("./test_inputs/random_corpus/net_032.cge", verify = 64, mode = compact)] struct Compact032; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_032.cge", verify = 64, mode = simd)] struct Simd032; }
+mod net_033 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_033.cge", verify = 64)] struct Net033; #[const_cge::network("./test_inputs/random_corpus/net_033.cge", verify = 64, mode = compact)] struct Compact033; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_033.cge", verify = 64, mode = simd)] struct Simd033; }
+mod net_034 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_034.cge", verify = 64)] struct Net034; #[const_cge::network("./test_inputs/random_corpus/net_034.cge", verify = 64, mode = compact)] struct Compact034; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_034.cge", verify = 64, mode = simd)] struct Simd034; }
+mod net_035 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_035.cge", verify = 64)] struct Net035; #[const_cge::network("./test_inputs/random_corpus/net_035.cge", verify = 64, mode = compact)] struct Compact035; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_035.cge", verify = 64, mode = simd)] struct Simd035; }
+mod net_036 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_036.cge", verify = 64)] struct Net036; #[const_cge::network("./test_inputs/random_corpus/net_036.cge", verify = 64, mode = compact)] struct Compact036; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_036.cge", verify = 64, mode = simd)] struct Simd036; }
+mod net_037 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_037.cge", verify = 64)] struct Net037; #[const_cge::network("./test_inputs/random_corpus/net_037.cge", verify = 64, mode = compact)] struct Compact037; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_037.cge", verify = 64, mode = simd)] struct Simd037; }
+mod net_038 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_038.cge", verify = 64)] struct Net038; #[const_cge::network("./test_inputs/random_corpus/net_038.cge", verify = 64, mode = compact)] struct Compact038; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_038.cge", verify = 64, mode = simd)] struct Simd038; }
+mod net_039 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_039.cge", verify = 64)] struct Net039; #[const_cge::network("./test_inputs/random_corpus/net_039.cge", verify = 64, mode = compact)] struct Compact039; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_039.cge", verify = 64, mode = simd)] struct Simd039; }
+mod net_040 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_040.cge", verify = 64)] struct Net040; #[const_cge::network("./test_inputs/random_corpus/net_040.cge", verify = 64, mode = compact)] struct Compact040; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_040.cge", verify = 64, mode = simd)] struct Simd040; }
+mod net_041 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_041.cge", verify = 64)] struct Net041; #[const_cge::network("./test_inputs/random_corpus/net_041.cge", verify = 64, mode = compact)] struct Compact041; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_041.cge", verify = 64, mode = simd)] struct Simd041; }
+mod net_042 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_042.cge", verify = 64)] struct Net042; #[const_cge::network("./test_inputs/random_corpus/net_042.cge", verify = 64, mode = compact)] struct Compact042; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_042.cge", verify = 64, mode = simd)] struct Simd042; }
+mod net_043 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_043.cge", verify = 64)] struct Net043; #[const_cge::network("./test_inputs/random_corpus/net_043.cge", verify = 64, mode = compact)] struct Compact043; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_043.cge", verify = 64, mode = simd)] struct Simd043; }
+mod net_044 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_044.cge", verify = 64)] struct Net044; #[const_cge::network("./test_inputs/random_corpus/net_044.cge", verify = 64, mode = compact)] struct Compact044; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_044.cge", verify = 64, mode = simd)] struct Simd044; }
+mod net_045 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_045.cge", verify = 64)] struct Net045; #[const_cge::network("./test_inputs/random_corpus/net_045.cge", verify = 64, mode = compact)] struct Compact045; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_045.cge", verify = 64, mode = simd)] struct Simd045; }
+mod net_046 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_046.cge", verify = 64)] struct Net046; #[const_cge::network("./test_inputs/random_corpus/net_046.cge", verify = 64, mode = compact)] struct Compact046; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_046.cge", verify = 64, mode = simd)] struct Simd046; }
+mod net_047 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_047.cge", verify = 64)] struct Net047; #[const_cge::network("./test_inputs/random_corpus/net_047.cge", verify = 64, mode = compact)] struct Compact047; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_047.cge", verify = 64, mode = simd)] struct Simd047; }
+mod net_048 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_048.cge", verify = 64)] struct Net048; #[const_cge::network("./test_inputs/random_corpus/net_048.cge", verify = 64, mode = compact)] struct Compact048; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_048.cge", verify = 64, mode = simd)] struct Simd048; }
+mod net_049 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_049.cge", verify = 64)] struct Net049; #[const_cge::network("./test_inputs/random_corpus/net_049.cge", verify = 64, mode = compact)] struct Compact049; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_049.cge", verify = 64, mode = simd)] struct Simd049; }
+mod net_050 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_050.cge", verify = 64)] struct Net050; #[const_cge::network("./test_inputs/random_corpus/net_050.cge", verify = 64, mode = compact)] struct Compact050; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_050.cge", verify = 64, mode = simd)] struct Simd050; }
+mod net_051 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_051.cge", verify = 64)] struct Net051; #[const_cge::network("./test_inputs/random_corpus/net_051.cge", verify = 64, mode = compact)] struct Compact051; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_051.cge", verify = 64, mode = simd)] struct Simd051; }
+mod net_052 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_052.cge", verify = 64)] struct Net052; #[const_cge::network("./test_inputs/random_corpus/net_052.cge", verify = 64, mode = compact)] struct Compact052; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_052.cge", verify = 64, mode = simd)] struct Simd052; }
+mod net_053 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_053.cge", verify = 64)] struct Net053; #[const_cge::network("./test_inputs/random_corpus/net_053.cge", verify = 64, mode = compact)] struct Compact053; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_053.cge", verify = 64, mode = simd)] struct Simd053; }
+mod net_054 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_054.cge", verify = 64)] struct Net054; #[const_cge::network("./test_inputs/random_corpus/net_054.cge", verify = 64, mode = compact)] struct Compact054; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_054.cge", verify = 64, mode = simd)] struct Simd054; }
+mod net_055 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_055.cge", verify = 64)] struct Net055; #[const_cge::network("./test_inputs/random_corpus/net_055.cge", verify = 64, mode = compact)] struct Compact055; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_055.cge", verify = 64, mode = simd)] struct Simd055; }
+mod net_056 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_056.cge", verify = 64)] struct Net056; #[const_cge::network("./test_inputs/random_corpus/net_056.cge", verify = 64, mode = compact)] struct Compact056; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_056.cge", verify = 64, mode = simd)] struct Simd056; }
+mod net_057 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_057.cge", verify = 64)] struct Net057; #[const_cge::network("./test_inputs/random_corpus/net_057.cge", verify = 64, mode = compact)] struct Compact057; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_057.cge", verify = 64, mode = simd)] struct Simd057; }
+mod net_058 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_058.cge", verify = 64)] struct Net058; #[const_cge::network("./test_inputs/random_corpus/net_058.cge", verify = 64, mode = compact)] struct Compact058; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_058.cge", verify = 64, mode = simd)] struct Simd058; }
+mod net_059 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_059.cge", verify = 64)] struct Net059; #[const_cge::network("./test_inputs/random_corpus/net_059.cge", verify = 64, mode = compact)] struct Compact059; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_059.cge", verify = 64, mode = simd)] struct Simd059; }
+mod net_060 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_060.cge", verify = 64)] struct Net060; #[const_cge::network("./test_inputs/random_corpus/net_060.cge", verify = 64, mode = compact)] struct Compact060; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_060.cge", verify = 64, mode = simd)] struct Simd060; }
+mod net_061 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_061.cge", verify = 64)] struct Net061; #[const_cge::network("./test_inputs/random_corpus/net_061.cge", verify = 64, mode = compact)] struct Compact061; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_061.cge", verify = 64, mode = simd)] struct Simd061; }
+mod net_062 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_062.cge", verify = 64)] struct Net062; #[const_cge::network("./test_inputs/random_corpus/net_062.cge", verify = 64, mode = compact)] struct Compact062; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_062.cge", verify = 64, mode = simd)] struct Simd062; }
+mod net_063 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_063.cge", verify = 64)] struct Net063; #[const_cge::network("./test_inputs/random_corpus/net_063.cge", verify = 64, mode = compact)] struct Compact063; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_063.cge", verify = 64, mode = simd)] struct Simd063; }
+mod net_064 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_064.cge", verify = 64)] struct Net064; #[const_cge::network("./test_inputs/random_corpus/net_064.cge", verify = 64, mode = compact)] struct Compact064; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_064.cge", verify = 64, mode = simd)] struct Simd064; }
+mod net_065 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_065.cge", verify = 64)] struct Net065; #[const_cge::network("./test_inputs/random_corpus/net_065.cge", verify = 64, mode = compact)] struct Compact065; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_065.cge", verify = 64, mode = simd)] struct Simd065; }
+mod net_066 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_066.cge", verify = 64)] struct Net066; #[const_cge::network("./test_inputs/random_corpus/net_066.cge", verify = 64, mode = compact)] struct Compact066; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_066.cge", verify = 64, mode = simd)] struct Simd066; }
+mod net_067 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_067.cge", verify = 64)] struct Net067; #[const_cge::network("./test_inputs/random_corpus/net_067.cge", verify = 64, mode = compact)] struct Compact067; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_067.cge", verify = 64, mode = simd)] struct Simd067; }
+mod net_068 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_068.cge", verify = 64)] struct Net068; #[const_cge::network("./test_inputs/random_corpus/net_068.cge", verify = 64, mode = compact)] struct Compact068; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_068.cge", verify = 64, mode = simd)] struct Simd068; }
+mod net_069 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_069.cge", verify = 64)] struct Net069; #[const_cge::network("./test_inputs/random_corpus/net_069.cge", verify = 64, mode = compact)] struct Compact069; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_069.cge", verify = 64, mode = simd)] struct Simd069; }
+mod net_070 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_070.cge", verify = 64)] struct Net070; #[const_cge::network("./test_inputs/random_corpus/net_070.cge", verify = 64, mode = compact)] struct Compact070; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_070.cge", verify = 64, mode = simd)] struct Simd070; }
+mod net_071 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_071.cge", verify = 64)] struct Net071; #[const_cge::network("./test_inputs/random_corpus/net_071.cge", verify = 64, mode = compact)] struct Compact071; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_071.cge", verify = 64, mode = simd)] struct Simd071; }
+mod net_072 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_072.cge", verify = 64)] struct Net072; #[const_cge::network("./test_inputs/random_corpus/net_072.cge", verify = 64, mode = compact)] struct Compact072; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_072.cge", verify = 64, mode = simd)] struct Simd072; }
+mod net_073 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_073.cge", verify = 64)] struct Net073; #[const_cge::network("./test_inputs/random_corpus/net_073.cge", verify = 64, mode = compact)] struct Compact073; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_073.cge", verify = 64, mode = simd)] struct Simd073; }
+mod net_074 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_074.cge", verify = 64)] struct Net074; #[const_cge::network("./test_inputs/random_corpus/net_074.cge", verify = 64, mode = compact)] struct Compact074; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_074.cge", verify = 64, mode = simd)] struct Simd074; }
+mod net_075 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_075.cge", verify = 64)] struct Net075; #[const_cge::network("./test_inputs/random_corpus/net_075.cge", verify = 64, mode = compact)] struct Compact075; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_075.cge", verify = 64, mode = simd)] struct Simd075; }
+mod net_076 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_076.cge", verify = 64)] struct Net076; #[const_cge::network("./test_inputs/random_corpus/net_076.cge", verify = 64, mode = compact)] struct Compact076; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_076.cge", verify = 64, mode = simd)] struct Simd076; }
+mod net_077 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_077.cge", verify = 64)] struct Net077; #[const_cge::network("./test_inputs/random_corpus/net_077.cge", verify = 64, mode = compact)] struct Compact077; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_077.cge", verify = 64, mode = simd)] struct Simd077; }
+mod net_078 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_078.cge", verify = 64)] struct Net078; #[const_cge::network("./test_inputs/random_corpus/net_078.cge", verify = 64, mode = compact)] struct Compact078; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_078.cge", verify = 64, mode = simd)] struct Simd078; }
+mod net_079 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_079.cge", verify = 64)] struct Net079; #[const_cge::network("./test_inputs/random_corpus/net_079.cge", verify = 64, mode = compact)] struct Compact079; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_079.cge", verify = 64, mode = simd)] struct Simd079; }
+mod net_080 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_080.cge", verify = 64)] struct Net080; #[const_cge::network("./test_inputs/random_corpus/net_080.cge", verify = 64, mode = compact)] struct Compact080; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_080.cge", verify = 64, mode = simd)] struct Simd080; }
+mod net_081 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_081.cge", verify = 64)] struct Net081; #[const_cge::network("./test_inputs/random_corpus/net_081.cge", verify = 64, mode = compact)] struct Compact081; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_081.cge", verify = 64, mode = simd)] struct Simd081; }
+mod net_082 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_082.cge", verify = 64)] struct Net082; #[const_cge::network("./test_inputs/random_corpus/net_082.cge", verify = 64, mode = compact)] struct Compact082; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_082.cge", verify = 64, mode = simd)] struct Simd082; }
+mod net_083 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_083.cge", verify = 64)] struct Net083; #[const_cge::network("./test_inputs/random_corpus/net_083.cge", verify = 64, mode = compact)] struct Compact083; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_083.cge", verify = 64, mode = simd)] struct Simd083; }
+mod net_084 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_084.cge", verify = 64)] struct Net084; #[const_cge::network("./test_inputs/random_corpus/net_084.cge", verify = 64, mode = compact)] struct Compact084; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_084.cge", verify = 64, mode = simd)] struct Simd084; }
+mod net_085 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_085.cge", verify = 64)] struct Net085; #[const_cge::network("./test_inputs/random_corpus/net_085.cge", verify = 64, mode = compact)] struct Compact085; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_085.cge", verify = 64, mode = simd)] struct Simd085; }
+mod net_086 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_086.cge", verify = 64)] struct Net086; #[const_cge::network("./test_inputs/random_corpus/net_086.cge", verify = 64, mode = compact)] struct Compact086; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_086.cge", verify = 64, mode = simd)] struct Simd086; }
+mod net_087 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_087.cge", verify = 64)] struct Net087; #[const_cge::network("./test_inputs/random_corpus/net_087.cge", verify = 64, mode = compact)] struct Compact087; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_087.cge", verify = 64, mode = simd)] struct Simd087; }
+mod net_088 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_088.cge", verify = 64)] struct Net088; #[const_cge::network("./test_inputs/random_corpus/net_088.cge", verify = 64, mode = compact)] struct Compact088; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_088.cge", verify = 64, mode = simd)] struct Simd088; }
+mod net_089 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_089.cge", verify = 64)] struct Net089; #[const_cge::network("./test_inputs/random_corpus/net_089.cge", verify = 64, mode = compact)] struct Compact089; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_089.cge", verify = 64, mode = simd)] struct Simd089; }
+mod net_090 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_090.cge", verify = 64)] struct Net090; #[const_cge::network("./test_inputs/random_corpus/net_090.cge", verify = 64, mode = compact)] struct Compact090; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_090.cge", verify = 64, mode = simd)] struct Simd090; }
+mod net_091 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_091.cge", verify = 64)] struct Net091; #[const_cge::network("./test_inputs/random_corpus/net_091.cge", verify = 64, mode = compact)] struct Compact091; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_091.cge", verify = 64, mode = simd)] struct Simd091; }
+mod net_092 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_092.cge", verify = 64)] struct Net092; #[const_cge::network("./test_inputs/random_corpus/net_092.cge", verify = 64, mode = compact)] struct Compact092; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_092.cge", verify = 64, mode = simd)] struct Simd092; }
+mod net_093 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_093.cge", verify = 64)] struct Net093; #[const_cge::network("./test_inputs/random_corpus/net_093.cge", verify = 64, mode = compact)] struct Compact093; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_093.cge", verify = 64, mode = simd)] struct Simd093; }
+mod net_094 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_094.cge", verify = 64)] struct Net094; #[const_cge::network("./test_inputs/random_corpus/net_094.cge", verify = 64, mode = compact)] struct Compact094; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_094.cge", verify = 64, mode = simd)] struct Simd094; }
+mod net_095 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_095.cge", verify = 64)] struct Net095; #[const_cge::network("./test_inputs/random_corpus/net_095.cge", verify = 64, mode = compact)] struct Compact095; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_095.cge", verify = 64, mode = simd)] struct Simd095; }
+mod net_096 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_096.cge", verify = 64)] struct Net096; #[const_cge::network("./test_inputs/random_corpus/net_096.cge", verify = 64, mode = compact)] struct Compact096; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_096.cge", verify = 64, mode = simd)] struct Simd096; }
+mod net_097 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_097.cge", verify = 64)] struct Net097; #[const_cge::network("./test_inputs/random_corpus/net_097.cge", verify = 64, mode = compact)] struct Compact097; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_097.cge", verify = 64, mode = simd)] struct Simd097; }
+mod net_098 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_098.cge", verify = 64)] struct Net098; #[const_cge::network("./test_inputs/random_corpus/net_098.cge", verify = 64, mode = compact)] struct Compact098; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_098.cge", verify = 64, mode = simd)] struct Simd098; }
+mod net_099 { use crate as const_cge; #[const_cge::network("./test_inputs/random_corpus/net_099.cge", verify = 64)] struct Net099; #[const_cge::network("./test_inputs/random_corpus/net_099.cge", verify = 64, mode = compact)] struct Compact099; #[cfg(feature = "simd")] #[const_cge::network("./test_inputs/random_corpus/net_099.cge", verify = 64, mode = simd)] struct Simd099; }